@@ -47,12 +47,12 @@ export function BetPanel({ market, onTxSuccess }: BetPanelProps) {
         const info = await connection.getAccountInfo(configPda);
         if (info && info.data.length >= 119) {
           // Config layout offsets (after 8-byte discriminator):
-          // 92: paused (1 byte)
+          // 92: pause_flags (1 byte, bit 0 = trading paused)
           // 93: min_bet_lamports (8 bytes)
           // 101: betting_cutoff_seconds (8 bytes)
           // 109: challenge_period_seconds (8 bytes)
           // 117: exit_fee_bps (2 bytes)
-          setPaused(info.data[92] === 1);
+          setPaused((info.data[92] & 0b001) !== 0);
           setMinBetLamports(Number(info.data.readBigUInt64LE(93)));
           setBettingCutoffSeconds(Number(info.data.readBigInt64LE(101)));
           setExitFeeBps(info.data.readUInt16LE(117));
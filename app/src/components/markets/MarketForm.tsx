@@ -44,8 +44,8 @@ export function MarketForm() {
           // min_liquidity_lamports at offset 72 (8 bytes u64)
           const minLiqLamports = configInfo.data.readBigUInt64LE(72);
           setMinLiquidity(Number(minLiqLamports) / 1e9);
-          // paused at offset 92 (1 byte bool)
-          setPaused(configInfo.data[92] === 1);
+          // pause_flags at offset 92 (1 byte, bit 1 = creation paused)
+          setPaused((configInfo.data[92] & 0b010) !== 0);
         }
       } catch (err) {
         console.error("Failed to fetch config:", err);
@@ -6,7 +6,7 @@ import {
   Transaction,
   SystemProgram,
 } from "@solana/web3.js";
-import { getMarketPda, getPositionPda, getConfigPda } from "@/lib/program";
+import { getMarketPda, getPositionPda, getConfigPda, encodeOptionU16, getVaultPda, getTraderProfilePda } from "@/lib/program";
 import { PROGRAM_ID, API_URL } from "@/lib/constants";
 
 export function useBuy() {
@@ -18,15 +18,19 @@ export function useBuy() {
     async (
       marketId: number,
       amountLamports: number,
-      side: boolean
+      side: boolean,
+      minSharesOut: number = 0,
+      maxPriceBps?: number
     ) => {
       if (!publicKey || !signTransaction) return;
       setLoading(true);
 
       try {
         const [marketPda] = getMarketPda(marketId);
+        const [vaultPda] = getVaultPda(marketPda);
         const [positionPda] = getPositionPda(marketPda, publicKey);
         const [configPda] = getConfigPda();
+        const [traderProfilePda] = getTraderProfilePda(publicKey);
 
         // Anchor discriminator for "buy"
         const discriminator = Buffer.from([
@@ -35,8 +39,12 @@ export function useBuy() {
         const amountBuf = Buffer.alloc(8);
         amountBuf.writeBigUInt64LE(BigInt(amountLamports));
         const sideBuf = Buffer.from([side ? 1 : 0]);
+        // Slippage bounds: min_shares_out (u64 LE) + max_price_bps (Option<u16>)
+        const minSharesBuf = Buffer.alloc(8);
+        minSharesBuf.writeBigUInt64LE(BigInt(minSharesOut));
+        const maxPriceBuf = encodeOptionU16(maxPriceBps);
 
-        const data = Buffer.concat([discriminator, amountBuf, sideBuf]);
+        const data = Buffer.concat([discriminator, amountBuf, sideBuf, minSharesBuf, maxPriceBuf]);
 
         const ix = {
           programId: PROGRAM_ID,
@@ -44,8 +52,14 @@ export function useBuy() {
             { pubkey: publicKey, isSigner: true, isWritable: true },
             { pubkey: configPda, isSigner: false, isWritable: false },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
             { pubkey: positionPda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            // Optional token accounts, skipped for SOL markets (program id = None)
+            { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+            // Optional trader profile: volume fee discount and stats
+            { pubkey: traderProfilePda, isSigner: false, isWritable: true },
           ],
           data,
         };
@@ -3,7 +3,7 @@
 import { useState, useCallback } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
-import { getConfigPda, getCreatorProfilePda } from "@/lib/program";
+import { getConfigPda, getCreatorProfilePda, getLiquidityPositionPda, getVaultPda, getCreatorBondPda } from "@/lib/program";
 import { PROGRAM_ID } from "@/lib/constants";
 
 export function useClaimCreatorFee() {
@@ -18,8 +18,11 @@ export function useClaimCreatorFee() {
 
       try {
         const marketPda = new PublicKey(marketPubkey);
+        const [vaultPda] = getVaultPda(marketPda);
         const [configPda] = getConfigPda();
         const [creatorProfilePda] = getCreatorProfilePda(publicKey);
+        const [creatorLiquidityPda] = getLiquidityPositionPda(marketPda, publicKey);
+        const [creatorBondPda] = getCreatorBondPda(marketPda);
 
         const discriminator = Buffer.from([
           26, 97, 138, 203, 132, 171, 141, 252,
@@ -31,7 +34,10 @@ export function useClaimCreatorFee() {
             { pubkey: publicKey, isSigner: true, isWritable: true },
             { pubkey: configPda, isSigner: false, isWritable: false },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
+            { pubkey: creatorLiquidityPda, isSigner: false, isWritable: true },
             { pubkey: creatorProfilePda, isSigner: false, isWritable: true },
+            { pubkey: creatorBondPda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           ],
           data: discriminator,
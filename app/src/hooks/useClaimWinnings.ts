@@ -3,7 +3,7 @@
 import { useState, useCallback } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
-import { getConfigPda, getPositionPda } from "@/lib/program";
+import { getConfigPda, getPositionPda, getVaultPda, getTraderProfilePda } from "@/lib/program";
 import { PROGRAM_ID, API_URL } from "@/lib/constants";
 
 export function useClaimWinnings() {
@@ -18,7 +18,9 @@ export function useClaimWinnings() {
 
       try {
         const marketPda = new PublicKey(marketPubkey);
+        const [vaultPda] = getVaultPda(marketPda);
         const [configPda] = getConfigPda();
+        const [traderProfilePda] = getTraderProfilePda(publicKey);
         const [positionPda] = getPositionPda(marketPda, publicKey);
 
         const discriminator = Buffer.from([
@@ -31,7 +33,9 @@ export function useClaimWinnings() {
             { pubkey: publicKey, isSigner: true, isWritable: true },
             { pubkey: configPda, isSigner: false, isWritable: false },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
             { pubkey: positionPda, isSigner: false, isWritable: true },
+            { pubkey: traderProfilePda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           ],
           data: discriminator,
@@ -77,8 +81,11 @@ export function useClaimWinnings() {
       setLoading(true);
 
       try {
+        const [configPda] = getConfigPda();
         const marketPda = new PublicKey(marketPubkey);
+        const [vaultPda] = getVaultPda(marketPda);
         const [positionPda] = getPositionPda(marketPda, publicKey);
+        const [traderProfilePda] = getTraderProfilePda(publicKey);
 
         const discriminator = Buffer.from([
           15, 16, 30, 161, 255, 228, 97, 60,
@@ -88,8 +95,11 @@ export function useClaimWinnings() {
           programId: PROGRAM_ID,
           keys: [
             { pubkey: publicKey, isSigner: true, isWritable: true },
+            { pubkey: configPda, isSigner: false, isWritable: false },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
             { pubkey: positionPda, isSigner: false, isWritable: true },
+            { pubkey: traderProfilePda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           ],
           data: discriminator,
@@ -3,7 +3,7 @@
 import { useState, useCallback } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { Transaction, SystemProgram } from "@solana/web3.js";
-import { getConfigPda, getMarketPda, getCreatorProfilePda } from "@/lib/program";
+import { getConfigPda, getMarketPda, getCreatorProfilePda, getLiquidityPositionPda, getVaultPda, getCreatorBondPda } from "@/lib/program";
 import { PROGRAM_ID } from "@/lib/constants";
 
 // Rent for market (867 bytes) + creator profile (73 bytes) + tx fee
@@ -44,6 +44,9 @@ export function useCreateMarket() {
         // Parse market_count (offset: 8 + 32 + 32 + 8 + 2 + 2 = 84, u64)
         const marketCount = configInfo.data.readBigUInt64LE(84);
         const [marketPda] = getMarketPda(Number(marketCount));
+        const [vaultPda] = getVaultPda(marketPda);
+        const [creatorLiquidityPda] = getLiquidityPositionPda(marketPda, publicKey);
+        const [creatorBondPda] = getCreatorBondPda(marketPda);
 
         // Build create_market instruction
         const discriminator = Buffer.from([
@@ -64,6 +67,10 @@ export function useCreateMarket() {
         const liquidityBuf = Buffer.alloc(8);
         liquidityBuf.writeBigUInt64LE(BigInt(liquidityLamports));
 
+        // No oracle condition, no designated resolver (Option::None)
+        const oracleBuf = Buffer.from([0]);
+        const resolverBuf = Buffer.from([0]);
+
         const data = Buffer.concat([
           discriminator,
           questionLen,
@@ -72,6 +79,8 @@ export function useCreateMarket() {
           sourceBytes,
           timestampBuf,
           liquidityBuf,
+          oracleBuf,
+          resolverBuf,
         ]);
 
         const ix = {
@@ -80,7 +89,10 @@ export function useCreateMarket() {
             { pubkey: publicKey, isSigner: true, isWritable: true },
             { pubkey: configPda, isSigner: false, isWritable: true },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
+            { pubkey: creatorLiquidityPda, isSigner: false, isWritable: true },
             { pubkey: creatorProfilePda, isSigner: false, isWritable: true },
+            { pubkey: creatorBondPda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           ],
           data,
@@ -2,8 +2,8 @@
 
 import { useState, useCallback } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
-import { PublicKey, Transaction } from "@solana/web3.js";
-import { getConfigPda, getPositionPda } from "@/lib/program";
+import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
+import { getConfigPda, getPositionPda, encodeOptionU16, getVaultPda, getTraderProfilePda } from "@/lib/program";
 import { PROGRAM_ID, API_URL } from "@/lib/constants";
 
 export function useSell() {
@@ -12,13 +12,15 @@ export function useSell() {
   const [loading, setLoading] = useState(false);
 
   const sell = useCallback(
-    async (marketPubkey: string, shares: number, side: boolean, marketId?: number, totalSharesBefore?: number, costBasis?: number) => {
+    async (marketPubkey: string, shares: number, side: boolean, marketId?: number, totalSharesBefore?: number, costBasis?: number, minSolOut: number = 0, minPriceBps?: number) => {
       if (!publicKey || !signTransaction) return;
       setLoading(true);
 
       try {
         const marketPda = new PublicKey(marketPubkey);
+        const [vaultPda] = getVaultPda(marketPda);
         const [configPda] = getConfigPda();
+        const [traderProfilePda] = getTraderProfilePda(publicKey);
         const [positionPda] = getPositionPda(marketPda, publicKey);
 
         // Anchor discriminator for "sell"
@@ -26,12 +28,15 @@ export function useSell() {
           51, 230, 133, 164, 1, 127, 131, 173,
         ]);
 
-        // Args: shares (u64 LE) + side (u8 bool)
+        // Args: shares (u64 LE) + side (u8 bool) + min_sol_out (u64 LE) + min_price_bps (Option<u16>)
         const sharesBuf = Buffer.alloc(8);
         sharesBuf.writeBigUInt64LE(BigInt(shares));
         const sideBuf = Buffer.from([side ? 1 : 0]);
+        const minSolBuf = Buffer.alloc(8);
+        minSolBuf.writeBigUInt64LE(BigInt(minSolOut));
+        const minPriceBuf = encodeOptionU16(minPriceBps);
 
-        const data = Buffer.concat([discriminator, sharesBuf, sideBuf]);
+        const data = Buffer.concat([discriminator, sharesBuf, sideBuf, minSolBuf, minPriceBuf]);
 
         const ix = {
           programId: PROGRAM_ID,
@@ -39,7 +44,10 @@ export function useSell() {
             { pubkey: publicKey, isSigner: true, isWritable: true },
             { pubkey: configPda, isSigner: false, isWritable: false },
             { pubkey: marketPda, isSigner: false, isWritable: true },
+            { pubkey: vaultPda, isSigner: false, isWritable: true },
             { pubkey: positionPda, isSigner: false, isWritable: true },
+            { pubkey: traderProfilePda, isSigner: false, isWritable: true },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           ],
           data,
         };
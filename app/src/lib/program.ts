@@ -27,9 +27,49 @@ export function getPositionPda(
   );
 }
 
+export function getLiquidityPositionPda(
+  marketPubkey: PublicKey,
+  providerPubkey: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("liquidity"), marketPubkey.toBuffer(), providerPubkey.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getVaultPda(marketPubkey: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), marketPubkey.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 export function getCreatorProfilePda(wallet: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("creator"), wallet.toBuffer()],
     PROGRAM_ID
   );
 }
+
+export function getCreatorBondPda(marketPubkey: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), marketPubkey.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getTraderProfilePda(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("trader"), wallet.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/** Borsh-encode an Option<u16> instruction argument. */
+export function encodeOptionU16(value?: number): Buffer {
+  if (value === undefined) return Buffer.from([0]);
+  const buf = Buffer.alloc(3);
+  buf.writeUInt8(1, 0);
+  buf.writeUInt16LE(value, 1);
+  return buf;
+}
@@ -372,6 +372,7 @@ async function submitOnChainResolution(
       Connection,
       Keypair,
       PublicKey,
+      SystemProgram,
       Transaction,
       TransactionInstruction,
     } = await import("@solana/web3.js");
@@ -415,21 +416,29 @@ async function submitOnChainResolution(
       [Buffer.from("creator"), creatorPubkey.toBuffer()],
       programPubkey
     );
+    const [resolverProfilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("resolver"), authority.publicKey.toBuffer()],
+      programPubkey
+    );
 
     // Build resolve_market instruction
     const crypto = await import("crypto");
     const hash = crypto.createHash("sha256").update("global:resolve_market").digest();
     const discriminator = hash.subarray(0, 8);
+    // Args: outcome (u8: 1 = YES, 0 = NO) + scalar_value (Option<i64>, None for binary)
     const outcomeBuf = Buffer.from([outcome ? 1 : 0]);
-    const instructionData = Buffer.concat([discriminator, outcomeBuf]);
+    const scalarValueBuf = Buffer.from([0]);
+    const instructionData = Buffer.concat([discriminator, outcomeBuf, scalarValueBuf]);
 
     const ix = new TransactionInstruction({
       programId: programPubkey,
       keys: [
-        { pubkey: authority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: authority.publicKey, isSigner: true, isWritable: true },
         { pubkey: configPda, isSigner: false, isWritable: false },
         { pubkey: marketPda, isSigner: false, isWritable: true },
         { pubkey: creatorProfilePda, isSigner: false, isWritable: true },
+        { pubkey: resolverProfilePda, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       ],
       data: instructionData,
     });
@@ -506,6 +515,18 @@ async function submitOnChainVoid(
       programPubkey
     );
 
+    // Treasury (receives slashed creator bonds) is at offset 40 of Config
+    const configInfo = await connection.getAccountInfo(configPda);
+    if (!configInfo) {
+      console.error(`[resolver] Config PDA not found on-chain: ${configPda.toBase58()}`);
+      return null;
+    }
+    const treasuryPubkey = new PublicKey(configInfo.data.subarray(40, 72));
+    const [creatorBondPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bond"), marketPda.toBuffer()],
+      programPubkey
+    );
+
     // Build void_market instruction
     const crypto = await import("crypto");
     const hash = crypto.createHash("sha256").update("global:void_market").digest();
@@ -515,7 +536,9 @@ async function submitOnChainVoid(
     const reasonBytes = Buffer.from(reason.slice(0, 200), "utf-8");
     const reasonLenBuf = Buffer.alloc(4);
     reasonLenBuf.writeUInt32LE(reasonBytes.length);
-    const instructionData = Buffer.concat([discriminator, reasonLenBuf, reasonBytes]);
+    // reason_code: VoidReason::Other (4); automated voids don't slash the creator bond
+    const reasonCodeBuf = Buffer.from([4]);
+    const instructionData = Buffer.concat([discriminator, reasonLenBuf, reasonBytes, reasonCodeBuf]);
 
     const ix = new TransactionInstruction({
       programId: programPubkey,
@@ -524,6 +547,8 @@ async function submitOnChainVoid(
         { pubkey: configPda, isSigner: false, isWritable: false },
         { pubkey: marketPda, isSigner: false, isWritable: true },
         { pubkey: creatorProfilePda, isSigner: false, isWritable: true },
+        { pubkey: treasuryPubkey, isSigner: false, isWritable: true },
+        { pubkey: creatorBondPda, isSigner: false, isWritable: true },
       ],
       data: instructionData,
     });
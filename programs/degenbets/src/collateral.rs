@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::Market;
+use crate::errors::DegenBetsError;
+
+// Every market's collateral lives in its own vault PDA, `[b"vault", market]`,
+// so the Market account only ever holds its own rent:
+//   - SOL markets: a data-less system account holding lamports. It keeps the
+//     rent-exempt minimum for a zero-byte account on top of the collateral.
+//   - Token markets: an SPL token account whose authority is the vault PDA.
+// Either way the vault signs for itself with the same seeds.
+
+/// Token accounts needed to move collateral for an SPL-token market.
+///
+/// SOL markets pass `None` for both optional accounts (they trail each
+/// instruction's account list, so clients can omit them).
+pub struct TokenCollateral<'a, 'info> {
+    pub token_account: &'a Account<'info, TokenAccount>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> TokenCollateral<'a, 'info> {
+    /// Returns `None` for SOL markets, and the token accounts for token markets
+    /// (failing if any of them were not supplied).
+    pub fn resolve(
+        market: &Market,
+        token_account: &'a Option<Account<'info, TokenAccount>>,
+        token_program: &'a Option<Program<'info, Token>>,
+    ) -> Result<Option<Self>> {
+        if market.collateral_mint == Pubkey::default() {
+            return Ok(None);
+        }
+
+        match (token_account, token_program) {
+            (Some(token_account), Some(token_program)) => {
+                require!(
+                    token_account.mint == market.collateral_mint,
+                    DegenBetsError::InvalidCollateralMint
+                );
+                Ok(Some(Self { token_account, token_program }))
+            }
+            _ => err!(DegenBetsError::MissingCollateralAccounts),
+        }
+    }
+}
+
+/// Create the market's vault: fund the rent-exempt minimum for SOL markets,
+/// or create and initialize the vault token account for token markets.
+pub fn init_vault<'info>(
+    market: &Account<'info, Market>,
+    vault: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    mint: Option<&Account<'info, Mint>>,
+    token: Option<&TokenCollateral<'_, 'info>>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let market_key = market.key();
+    let bump = [market.vault_bump];
+    let seeds: &[&[u8]] = &[b"vault", market_key.as_ref(), &bump];
+
+    match (token, mint) {
+        (None, _) => system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: vault.clone(),
+                },
+            ),
+            rent.minimum_balance(0),
+        ),
+        (Some(token), Some(mint)) => {
+            let required = rent.minimum_balance(TokenAccount::LEN);
+            if vault.lamports() == 0 {
+                system_program::create_account(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        system_program::CreateAccount {
+                            from: payer.to_account_info(),
+                            to: vault.clone(),
+                        },
+                        &[seeds],
+                    ),
+                    required,
+                    TokenAccount::LEN as u64,
+                    &token.token_program.key(),
+                )?;
+            } else {
+                // The address is predictable and may already hold lamports,
+                // which makes create_account fail: top up, allocate and assign
+                let top_up = required.saturating_sub(vault.lamports());
+                if top_up > 0 {
+                    system_program::transfer(
+                        CpiContext::new(
+                            system_program.to_account_info(),
+                            system_program::Transfer {
+                                from: payer.to_account_info(),
+                                to: vault.clone(),
+                            },
+                        ),
+                        top_up,
+                    )?;
+                }
+                system_program::allocate(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        system_program::Allocate { account_to_allocate: vault.clone() },
+                        &[seeds],
+                    ),
+                    TokenAccount::LEN as u64,
+                )?;
+                system_program::assign(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        system_program::Assign { account_to_assign: vault.clone() },
+                        &[seeds],
+                    ),
+                    &token.token_program.key(),
+                )?;
+            }
+            token::initialize_account3(CpiContext::new(
+                token.token_program.to_account_info(),
+                token::InitializeAccount3 {
+                    account: vault.clone(),
+                    mint: mint.to_account_info(),
+                    authority: vault.clone(),
+                },
+            ))
+        }
+        (Some(_), None) => err!(DegenBetsError::MissingCollateralAccounts),
+    }
+}
+
+/// Move `amount` of collateral from `payer` into the market's vault.
+pub fn deposit<'info>(
+    vault: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    token: Option<&TokenCollateral<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    match token {
+        None => system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: vault.clone(),
+                },
+            ),
+            amount,
+        ),
+        Some(token) => token::transfer(
+            CpiContext::new(
+                token.token_program.to_account_info(),
+                token::Transfer {
+                    from: token.token_account.to_account_info(),
+                    to: vault.clone(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Move `amount` of collateral out of the market's vault to `recipient`
+/// (the token account in `token` for token markets).
+pub fn withdraw<'info>(
+    market: &Account<'info, Market>,
+    vault: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    token: Option<&TokenCollateral<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let market_key = market.key();
+    let bump = [market.vault_bump];
+    let seeds: &[&[u8]] = &[b"vault", market_key.as_ref(), &bump];
+
+    match token {
+        None => {
+            // Rent-exemption guard: the vault itself must stay rent-exempt
+            let rent = Rent::get()?;
+            require!(
+                vault.lamports().saturating_sub(amount) >= rent.minimum_balance(0),
+                DegenBetsError::InsufficientRentBalance
+            );
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: vault.clone(),
+                        to: recipient.clone(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            )
+        }
+        Some(token) => token::transfer(
+            CpiContext::new_with_signer(
+                token.token_program.to_account_info(),
+                token::Transfer {
+                    from: vault.clone(),
+                    to: token.token_account.to_account_info(),
+                    authority: vault.clone(),
+                },
+                &[seeds],
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Sweep everything left in the vault to `recipient` and close it.
+pub fn close_vault<'info>(
+    market: &Account<'info, Market>,
+    vault: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    token: Option<&TokenCollateral<'_, 'info>>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let market_key = market.key();
+    let bump = [market.vault_bump];
+    let seeds: &[&[u8]] = &[b"vault", market_key.as_ref(), &bump];
+
+    match token {
+        None => system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: vault.clone(),
+                    to: recipient.clone(),
+                },
+                &[seeds],
+            ),
+            vault.lamports(),
+        ),
+        Some(token) => {
+            let remaining = vault_collateral(market, vault)?;
+            withdraw(market, vault, recipient, Some(token), system_program, remaining)?;
+            token::close_account(CpiContext::new_with_signer(
+                token.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: vault.clone(),
+                    destination: recipient.clone(),
+                    authority: vault.clone(),
+                },
+                &[seeds],
+            ))
+        }
+    }
+}
+
+/// Move lamports out of a program-owned escrow account (creator bonds,
+/// dispute bonds).
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_balance = from.lamports()
+        .checked_sub(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let to_balance = to.lamports()
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+    Ok(())
+}
+
+/// Collateral currently held by the vault, excluding its own rent.
+pub fn vault_collateral(market: &Market, vault: &AccountInfo) -> Result<u64> {
+    if market.collateral_mint == Pubkey::default() {
+        let rent = Rent::get()?;
+        return Ok(vault.lamports().saturating_sub(rent.minimum_balance(0)));
+    }
+
+    let data = vault.try_borrow_data()?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+    Ok(token_account.amount)
+}
+
+/// Reconcile the vault against `total_minted`: every outstanding complete set
+/// must be backed by one unit of collateral.
+pub fn assert_backed(market: &Market, vault: &AccountInfo) -> Result<()> {
+    require!(
+        vault_collateral(market, vault)? >= market.total_minted,
+        DegenBetsError::VaultUndercollateralized
+    );
+    Ok(())
+}
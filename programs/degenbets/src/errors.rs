@@ -89,6 +89,122 @@ pub enum DegenBetsError {
     #[msg("AMM pool is empty")]
     EmptyPool,
 
-    #[msg("Sell your existing position before buying the opposite side")]
-    OppositePositionExists,
+    #[msg("Trade exceeds slippage bounds")]
+    SlippageExceeded,
+
+    #[msg("Market has outstanding liquidity")]
+    LiquidityOutstanding,
+    #[msg("Collateral mint is not allowed")]
+    CollateralNotAllowed,
+
+    #[msg("Token account mint does not match market collateral")]
+    InvalidCollateralMint,
+
+    #[msg("Token accounts are required for this market's collateral")]
+    MissingCollateralAccounts,
+
+    #[msg("Collateral mint allowlist is full")]
+    TooManyCollateralMints,
+
+    #[msg("Vault holds less collateral than outstanding sets")]
+    VaultUndercollateralized,
+
+    #[msg("Challenge period has ended")]
+    ChallengePeriodEnded,
+
+    #[msg("Market is not disputed")]
+    MarketNotDisputed,
+
+    #[msg("Only position holders can dispute a resolution")]
+    NoPositionHeld,
+
+    #[msg("Instruction does not support this market type")]
+    InvalidMarketType,
+
+    #[msg("Categorical markets need between 3 and 16 outcomes")]
+    InvalidOutcomeCount,
+
+    #[msg("Outcome label too long (max 32 chars)")]
+    OutcomeLabelTooLong,
+
+    #[msg("Outcome index out of range")]
+    InvalidOutcomeIndex,
+
+    #[msg("Scalar markets resolve to a value")]
+    MissingScalarValue,
+
+    #[msg("Scalar upper bound must exceed lower bound")]
+    InvalidScalarBounds,
+
+    #[msg("Oracle resolution is not configured for this market")]
+    OracleNotConfigured,
+
+    #[msg("Price account does not match the market's oracle feed")]
+    InvalidOracleAccount,
+
+    #[msg("Oracle price is not available")]
+    OraclePriceUnavailable,
+
+    #[msg("Oracle price is stale")]
+    OraclePriceStale,
+
+    #[msg("Oracle confidence interval too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("Signer is not a resolver committee member")]
+    NotCommitteeMember,
+
+    #[msg("Committee needs 1-10 unique members and a threshold within the member count")]
+    InvalidCommittee,
+
+    #[msg("Signer is neither the authority nor the market's resolver")]
+    UnauthorizedResolver,
+
+    #[msg("Resolver profile of the market's resolver is required")]
+    MissingResolverProfile,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+
+    #[msg("Config changes are timelocked; queue them instead")]
+    ConfigTimelocked,
+
+    #[msg("Config change timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Market is paused")]
+    MarketPaused,
+
+    #[msg("Market has been traded and can no longer be cancelled")]
+    MarketAlreadyTraded,
+
+    #[msg("Creator bond must be returned before closing")]
+    CreatorBondOutstanding,
+
+    #[msg("The creator's liquidity is claimed with claim_creator_fee")]
+    CreatorLiquidityViaFeeClaim,
+
+    #[msg("Oracle price was published too long after the resolution time")]
+    OraclePriceTooLate,
+
+    #[msg("Account is not a legacy account of the expected type")]
+    InvalidMigrationAccount,
+
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Voided market still has unclaimed refunds or liquidity")]
+    VoidClaimsOutstanding,
+
+    #[msg("A dispute is settled with a correction or a void, not both")]
+    ConflictingDisputeSettlement,
+
+    #[msg("Oracle price is not the first published after the resolution time")]
+    OraclePriceNotFirst,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{AdminRole, CommitteeVote, ConfigParams, VoidReason};
 
 #[event]
 pub struct MarketCreated {
@@ -9,6 +10,13 @@ pub struct MarketCreated {
     pub resolution_timestamp: i64,
     pub liquidity_amount: u64,
     pub market_id: u64,
+    pub collateral_mint: Pubkey,
+    /// Outcome labels for categorical markets, empty for binary
+    pub outcomes: Vec<String>,
+    /// Range for scalar markets, zero otherwise
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+    pub resolver: Option<Pubkey>,
 }
 
 #[event]
@@ -19,6 +27,13 @@ pub struct SharesBought {
     pub sol_amount: u64,
     pub shares_received: u64,
     pub price_after: u64,
+    pub min_shares_out: u64,
+    pub max_price_bps: Option<u16>,
+    /// Swap fee applied after any volume tier discount
+    pub fee_bps: u16,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
 }
 
 #[event]
@@ -29,21 +44,118 @@ pub struct SharesSold {
     pub shares_sold: u64,
     pub sol_received: u64,
     pub price_after: u64,
+    pub min_sol_out: u64,
+    pub min_price_bps: Option<u16>,
+    /// Swap fee applied after any volume tier discount
+    pub fee_bps: u16,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct SetsSplit {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct SetsMerged {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub pairs: u64,
+    pub sol_received: u64,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub lp_shares_minted: u64,
+    pub yes_returned: u64,
+    pub no_returned: u64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub lp_shares_burned: u64,
+    pub yes_out: u64,
+    pub no_out: u64,
+}
+
+#[event]
+pub struct LiquidityClaimed {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub lp_shares_burned: u64,
+    pub amount: u64,
 }
 
 #[event]
 pub struct MarketResolved {
     pub market: Pubkey,
+    /// Binary markets only (false for categorical)
     pub outcome: bool,
+    pub winning_outcome: u8,
+    /// Scalar markets only
+    pub resolved_value: Option<i64>,
     pub total_pot: u64,
     pub treasury_fee: u64,
     pub creator_fee: u64,
 }
 
+/// Emitted alongside `MarketResolved` when a price feed settled the market
+#[event]
+pub struct OracleResolved {
+    pub market: Pubkey,
+    pub feed: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+    pub caller: Pubkey,
+}
+
+#[event]
+pub struct MarketCancelled {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub liquidity_returned: u64,
+}
+
 #[event]
 pub struct MarketVoided {
     pub market: Pubkey,
     pub reason: String,
+    pub reason_code: VoidReason,
+    /// Creator bond slashed to the treasury (creator-fault voids only)
+    pub bond_slashed: u64,
+}
+
+#[event]
+pub struct ResolutionVoteCast {
+    pub market: Pubkey,
+    pub voter: Pubkey,
+    pub vote: CommitteeVote,
+    /// Matching votes from current members, including this one
+    pub votes_for: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ResolverCommitteeUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
 }
 
 #[event]
@@ -51,6 +163,9 @@ pub struct WinningsClaimed {
     pub market: Pubkey,
     pub user: Pubkey,
     pub amount: u64,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
 }
 
 #[event]
@@ -58,6 +173,7 @@ pub struct CreatorFeeClaimed {
     pub market: Pubkey,
     pub creator: Pubkey,
     pub amount: u64,
+    pub bond_returned: u64,
 }
 
 #[event]
@@ -65,6 +181,9 @@ pub struct RefundClaimed {
     pub market: Pubkey,
     pub user: Pubkey,
     pub amount: u64,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
 }
 
 #[event]
@@ -75,11 +194,162 @@ pub struct TreasuryFeeClaimed {
 
 #[event]
 pub struct PlatformPauseToggled {
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct MarketPauseToggled {
+    pub market: Pubkey,
     pub paused: bool,
 }
 
+#[event]
+pub struct CollateralMintUpdated {
+    pub mint: Pubkey,
+    pub allowed: bool,
+    pub min_liquidity: u64,
+    pub min_trade: u64,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub min_liquidity_lamports: u64,
+    pub treasury_rake_bps: u16,
+    pub creator_rake_bps: u16,
+    pub min_trade_lamports: u64,
+    pub betting_cutoff_seconds: i64,
+    pub challenge_period_seconds: i64,
+    pub swap_fee_bps: u16,
+    pub dispute_bond_lamports: u64,
+    pub config_timelock_seconds: i64,
+    pub creator_bond_lamports: u64,
+}
+
+/// Fields changed by a config update; `old` and `new` set the same fields
+#[event]
+pub struct ConfigUpdated {
+    pub old: ConfigParams,
+    pub new: ConfigParams,
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub proposer: Pubkey,
+    pub params: ConfigParams,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub executor: Pubkey,
+    pub params: ConfigParams,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub params: ConfigParams,
+}
+
+#[event]
+pub struct RoleUpdated {
+    pub role: AdminRole,
+    pub old_holder: Pubkey,
+    pub new_holder: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
 #[event]
 pub struct AuthorityTransferred {
     pub old_authority: Pubkey,
     pub new_authority: Pubkey,
 }
+
+#[event]
+pub struct ResolutionDisputed {
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub disputed_outcome: u8,
+    pub bond: u64,
+}
+
+#[event]
+pub struct DisputeSettled {
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub overturned: bool,
+    pub winning_outcome: u8,
+    pub resolved_value: Option<i64>,
+    pub bond: u64,
+    pub reward: u64,
+    /// Market was voided instead of corrected
+    pub voided: bool,
+}
+
+#[event]
+pub struct OutcomeSharesBought {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub outcome_index: u8,
+    pub sol_amount: u64,
+    pub shares_received: u64,
+    pub prices_bps: Vec<u64>,
+    /// Swap fee applied after any volume tier discount
+    pub fee_bps: u16,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct OutcomeSharesSold {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub outcome_index: u8,
+    pub shares_sold: u64,
+    pub sol_received: u64,
+    pub prices_bps: Vec<u64>,
+    /// Swap fee applied after any volume tier discount
+    pub fee_bps: u16,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct CreatorProfileMigrated {
+    pub creator_profile: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub config: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct MarketMigrated {
+    pub market: Pubkey,
+    pub payer: Pubkey,
+    /// Collateral moved from the market account into its new vault
+    pub collateral: u64,
+}
+
+#[event]
+pub struct PositionMigrated {
+    pub position: Pubkey,
+    pub payer: Pubkey,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::Config;
+use crate::errors::DegenBetsError;
+use crate::events::AuthorityTransferred;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pending = config.pending_authority.ok_or(DegenBetsError::NoPendingAuthority)?;
+    require!(
+        pending == ctx.accounts.new_authority.key(),
+        DegenBetsError::NotPendingAuthority
+    );
+
+    let old_authority = config.authority;
+    config.authority = pending;
+    config.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: pending,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::LiquidityAdded;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = LiquidityPosition::SIZE,
+        seeds = [b"liquidity", market.key().as_ref(), provider.key().as_ref()],
+        bump,
+    )]
+    pub liquidity_position: Account<'info, LiquidityPosition>,
+
+    /// Receives the outcome shares not absorbed by the pool at its current ratio
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = Position::SIZE,
+        seeds = [b"position", market.key().as_ref(), provider.key().as_ref()],
+        bump,
+        constraint = !position.claimed @ DegenBetsError::AlreadyClaimed,
+    )]
+    pub position: Account<'info, Position>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = provider)]
+    pub provider_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    let market = &ctx.accounts.market;
+    require!(
+        amount >= config.min_trade_for(&market.collateral_mint)?,
+        DegenBetsError::BelowMinBet
+    );
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < market.resolution_timestamp - config.betting_cutoff_seconds,
+        DegenBetsError::BettingClosed
+    );
+
+    let (lp_minted, yes_added, no_added) = math::calc_add_liquidity(
+        amount,
+        market.yes_reserve,
+        market.no_reserve,
+        market.total_lp_shares,
+    )?;
+
+    // Transfer collateral from provider to market
+    let token = TokenCollateral::resolve(
+        &ctx.accounts.market,
+        &ctx.accounts.provider_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::deposit(
+        &ctx.accounts.vault,
+        &ctx.accounts.provider,
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    // Mint `amount` complete sets; the pool keeps yes_added / no_added of them
+    let market = &mut ctx.accounts.market;
+    market.total_minted = market.total_minted
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.yes_reserve = market.yes_reserve
+        .checked_add(yes_added)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.no_reserve = market.no_reserve
+        .checked_add(no_added)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.total_lp_shares = market.total_lp_shares
+        .checked_add(lp_minted)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let liquidity_position = &mut ctx.accounts.liquidity_position;
+    if liquidity_position.market == Pubkey::default() {
+        liquidity_position.market = market.key();
+        liquidity_position.provider = ctx.accounts.provider.key();
+        liquidity_position.bump = ctx.bumps.liquidity_position;
+    }
+    liquidity_position.lp_shares = liquidity_position.lp_shares
+        .checked_add(lp_minted)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Return the surplus outcome shares to the provider's position, at
+    // their pool value (adding liquidity doesn't move the price)
+    let yes_returned = amount - yes_added;
+    let no_returned = amount - no_added;
+    let (yes_cost, no_cost) = math::calc_shares_value(
+        yes_returned,
+        no_returned,
+        market.yes_reserve,
+        market.no_reserve,
+    );
+    let position = &mut ctx.accounts.position;
+    if position.market == Pubkey::default() {
+        position.market = market.key();
+        position.user = ctx.accounts.provider.key();
+        position.claimed = false;
+        position.bump = ctx.bumps.position;
+    }
+    position.record_cost(yes_cost, no_cost)?;
+    position.yes_shares = position.yes_shares
+        .checked_add(yes_returned)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    position.no_shares = position.no_shares
+        .checked_add(no_returned)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(market, &ctx.accounts.vault)?;
+
+    emit!(LiquidityAdded {
+        market: market.key(),
+        provider: ctx.accounts.provider.key(),
+        amount,
+        lp_shares_minted: lp_minted,
+        yes_returned,
+        no_returned,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::SharesBought;
 use crate::math;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct Buy<'info> {
@@ -20,9 +21,18 @@ pub struct Buy<'info> {
         mut,
         seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -34,15 +44,40 @@ pub struct Buy<'info> {
     pub position: Account<'info, Position>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Opt-in trading record; earns the volume fee discount and tracks stats
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Option<Account<'info, TraderProfile>>,
 }
 
-pub fn handler(ctx: Context<Buy>, amount: u64, side: bool) -> Result<()> {
+pub fn handler(
+    ctx: Context<Buy>,
+    amount: u64,
+    side: bool,
+    min_shares_out: u64,
+    max_price_bps: Option<u16>,
+) -> Result<()> {
     let config = &ctx.accounts.config;
 
-    require!(!config.paused, DegenBetsError::PlatformPaused);
-    require!(amount >= config.min_trade_lamports, DegenBetsError::BelowMinBet);
-
     let market = &ctx.accounts.market;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    require!(
+        amount >= config.min_trade_for(&market.collateral_mint)?,
+        DegenBetsError::BelowMinBet
+    );
     require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
     require!(market.yes_reserve > 0 && market.no_reserve > 0, DegenBetsError::EmptyPool);
 
@@ -52,36 +87,38 @@ pub fn handler(ctx: Context<Buy>, amount: u64, side: bool) -> Result<()> {
         DegenBetsError::BettingClosed
     );
 
-    // Enforce one side per wallet: can't buy YES if holding NO, and vice versa
-    let position = &ctx.accounts.position;
-    if position.market != Pubkey::default() {
-        if side {
-            require!(position.no_shares == 0, DegenBetsError::OppositePositionExists);
-        } else {
-            require!(position.yes_shares == 0, DegenBetsError::OppositePositionExists);
-        }
-    }
-
-    // Transfer SOL from user to market PDA
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.market.to_account_info(),
-            },
-        ),
+    // Transfer collateral from user to market
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::deposit(
+        &ctx.accounts.vault,
+        &ctx.accounts.user,
+        token.as_ref(),
+        &ctx.accounts.system_program,
         amount,
     )?;
 
-    // Calculate shares via AMM (mint complete sets + swap)
+    // Calculate shares via AMM (mint complete sets + swap), at the trader's tier fee
+    let fee_bps = config.trade_fee_bps(&ctx.accounts.market, ctx.accounts.trader_profile.as_deref());
     let market = &mut ctx.accounts.market;
     let (shares_out, new_ry, new_rn) = if side {
-        math::calc_buy_yes(amount, market.yes_reserve, market.no_reserve, market.swap_fee_bps)?
+        math::calc_buy_yes(amount, market.yes_reserve, market.no_reserve, fee_bps)?
     } else {
-        math::calc_buy_no(amount, market.yes_reserve, market.no_reserve, market.swap_fee_bps)?
+        math::calc_buy_no(amount, market.yes_reserve, market.no_reserve, fee_bps)?
     };
 
+    // Slippage: bound the fill against trades landing ahead of this one
+    require!(shares_out >= min_shares_out, DegenBetsError::SlippageExceeded);
+    if let Some(max_bps) = max_price_bps {
+        require!(
+            math::side_price_bps(new_ry, new_rn, side) <= max_bps as u64,
+            DegenBetsError::SlippageExceeded
+        );
+    }
+
     // Update market state
     market.yes_reserve = new_ry;
     market.no_reserve = new_rn;
@@ -91,25 +128,31 @@ pub fn handler(ctx: Context<Buy>, amount: u64, side: bool) -> Result<()> {
 
     // Update position
     let position = &mut ctx.accounts.position;
-    if position.market == Pubkey::default() {
+    let new_position = position.market == Pubkey::default();
+    if new_position {
         position.market = market.key();
         position.user = ctx.accounts.user.key();
         position.claimed = false;
         position.bump = ctx.bumps.position;
     }
 
-    if side {
-        position.yes_shares = position.yes_shares
-            .checked_add(shares_out)
-            .ok_or(DegenBetsError::MathOverflow)?;
-    } else {
-        position.no_shares = position.no_shares
-            .checked_add(shares_out)
-            .ok_or(DegenBetsError::MathOverflow)?;
-    }
+    let (yes_cost, no_cost) = if side { (amount, 0) } else { (0, amount) };
+    position.record_cost(yes_cost, no_cost)?;
+    let held = position.shares_mut(side);
+    *held = held
+        .checked_add(shares_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
 
     let price_after = math::price_yes_bps(market.yes_reserve, market.no_reserve);
 
+    if let Some(trader) = ctx.accounts.trader_profile.as_mut() {
+        trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile.unwrap_or_default());
+        trader.record_trade(amount, new_position)?;
+    }
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(market, &ctx.accounts.vault)?;
+
     emit!(SharesBought {
         market: market.key(),
         user: ctx.accounts.user.key(),
@@ -117,6 +160,12 @@ pub fn handler(ctx: Context<Buy>, amount: u64, side: bool) -> Result<()> {
         sol_amount: amount,
         shares_received: shares_out,
         price_after,
+        min_shares_out,
+        max_price_bps,
+        fee_bps,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
     });
 
     Ok(())
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::errors::DegenBetsError;
+use crate::math;
+use super::buy::{self, Buy};
+
+/// Exact-output buy: solve for the SOL needed to receive `shares`, then run the
+/// regular buy with that amount and `shares` as the slippage floor.
+pub fn handler(ctx: Context<Buy>, shares: u64, side: bool, max_sol_in: u64) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let fee_bps = ctx.accounts.config.trade_fee_bps(market, ctx.accounts.trader_profile.as_deref());
+    require!(market.yes_reserve > 0 && market.no_reserve > 0, DegenBetsError::EmptyPool);
+
+    let (sol_in, _, _) = if side {
+        math::calc_buy_yes_exact_shares(shares, market.yes_reserve, market.no_reserve, fee_bps)?
+    } else {
+        math::calc_buy_no_exact_shares(shares, market.yes_reserve, market.no_reserve, fee_bps)?
+    };
+    require!(sol_in <= max_sol_in, DegenBetsError::SlippageExceeded);
+
+    buy::handler(ctx, sol_in, side, shares, None)
+}
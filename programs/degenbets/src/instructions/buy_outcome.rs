@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::OutcomeSharesBought;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct BuyOutcome<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type == MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = OutcomePosition::space_for(market.outcome_reserves.len()),
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = !position.claimed @ DegenBetsError::AlreadyClaimed,
+    )]
+    pub position: Account<'info, OutcomePosition>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Opt-in trading record; earns the volume fee discount and tracks stats
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Option<Account<'info, TraderProfile>>,
+}
+
+/// Buy one outcome of a categorical market through the N-outcome pool.
+pub fn handler(
+    ctx: Context<BuyOutcome>,
+    amount: u64,
+    outcome_index: u8,
+    min_shares_out: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    let market = &ctx.accounts.market;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    require!(
+        amount >= config.min_trade_for(&market.collateral_mint)?,
+        DegenBetsError::BelowMinBet
+    );
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+    require!(
+        (outcome_index as usize) < market.outcome_reserves.len(),
+        DegenBetsError::InvalidOutcomeIndex
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < market.resolution_timestamp - config.betting_cutoff_seconds,
+        DegenBetsError::BettingClosed
+    );
+
+    // Transfer collateral from user to vault
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::deposit(
+        &ctx.accounts.vault,
+        &ctx.accounts.user,
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    // Calculate shares via AMM (mint complete sets + swap the other outcomes)
+    let fee_bps = ctx.accounts.config
+        .trade_fee_bps(&ctx.accounts.market, ctx.accounts.trader_profile.as_deref());
+    let market = &mut ctx.accounts.market;
+    let (shares_out, new_reserves) = math::calc_buy_outcome(
+        amount,
+        &market.outcome_reserves,
+        outcome_index as usize,
+        fee_bps,
+    )?;
+
+    // Slippage: bound the fill against trades landing ahead of this one
+    require!(shares_out >= min_shares_out, DegenBetsError::SlippageExceeded);
+
+    // Update market state
+    market.outcome_reserves = new_reserves;
+    market.total_minted = market.total_minted
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Update position
+    let position = &mut ctx.accounts.position;
+    let new_position = position.market == Pubkey::default();
+    if new_position {
+        position.market = market.key();
+        position.user = ctx.accounts.user.key();
+        position.shares = vec![0; market.outcome_reserves.len()];
+        position.cost_basis = vec![0; market.outcome_reserves.len()];
+        position.claimed = false;
+        position.bump = ctx.bumps.position;
+    }
+
+    position.record_cost(outcome_index as usize, amount)?;
+    let held = &mut position.shares[outcome_index as usize];
+    *held = held
+        .checked_add(shares_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    if let Some(trader) = ctx.accounts.trader_profile.as_mut() {
+        trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile.unwrap_or_default());
+        trader.record_trade(amount, new_position)?;
+    }
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(market, &ctx.accounts.vault)?;
+
+    emit!(OutcomeSharesBought {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        outcome_index,
+        sol_amount: amount,
+        shares_received: shares_out,
+        prices_bps: math::outcome_prices_bps(&market.outcome_reserves),
+        fee_bps,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::Config;
+use crate::errors::DegenBetsError;
+use crate::events::AuthorityTransferCancelled;
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pending = config.pending_authority.take().ok_or(DegenBetsError::NoPendingAuthority)?;
+
+    emit!(AuthorityTransferCancelled {
+        authority: config.authority,
+        pending_authority: pending,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ConfigChangeCancelled;
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"config_change"],
+        bump = pending_change.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// Proposer receives the pending change's rent back
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelConfigChange>) -> Result<()> {
+    emit!(ConfigChangeCancelled {
+        params: ctx.accounts.pending_change.params.clone(),
+    });
+
+    Ok(())
+}
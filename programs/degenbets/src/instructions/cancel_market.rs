@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::MarketCancelled;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct CancelMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = creator,
+        close = creator,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"liquidity", market.key().as_ref(), creator.key().as_ref()],
+        bump = creator_liquidity.bump,
+        close = creator,
+    )]
+    pub creator_liquidity: Account<'info, LiquidityPosition>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", market.key().as_ref()],
+        bump = creator_bond.bump,
+        close = creator,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = creator)]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Creator takes back an open market nobody else has touched yet. Returns the
+/// liquidity, the bond and all rent; unlike a void, reputation is unaffected.
+pub fn handler(ctx: Context<CancelMarket>) -> Result<()> {
+    let market = &ctx.accounts.market;
+
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+    require!(market.is_untouched(), DegenBetsError::MarketAlreadyTraded);
+
+    let liquidity_returned = market.initial_liquidity;
+
+    // Return the liquidity (and vault rent) to the creator and close the vault
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::close_vault(
+        market,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator.to_account_info(),
+        token.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    // A cancelled market doesn't count towards the creator's history
+    let profile = &mut ctx.accounts.creator_profile;
+    profile.markets_created = profile.markets_created.saturating_sub(1);
+
+    emit!(MarketCancelled {
+        market: ctx.accounts.market.key(),
+        creator: ctx.accounts.creator.key(),
+        liquidity_returned,
+    });
+
+    Ok(())
+}
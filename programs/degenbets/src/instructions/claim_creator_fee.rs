@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::CreatorFeeClaimed;
+use super::claim_liquidity;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct ClaimCreatorFee<'info> {
@@ -22,6 +25,24 @@ pub struct ClaimCreatorFee<'info> {
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// The creator's own share of the pool, settled alongside the fee
+    #[account(
+        mut,
+        seeds = [b"liquidity", market.key().as_ref(), creator.key().as_ref()],
+        bump = creator_liquidity.bump,
+        constraint = creator_liquidity.market == market.key(),
+        constraint = creator_liquidity.provider == creator.key(),
+    )]
+    pub creator_liquidity: Account<'info, LiquidityPosition>,
+
     #[account(
         mut,
         seeds = [b"creator", creator.key().as_ref()],
@@ -29,13 +50,30 @@ pub struct ClaimCreatorFee<'info> {
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
 
+    /// Bond escrow, closed back to the creator with whatever bond is left
+    #[account(
+        mut,
+        seeds = [b"bond", market.key().as_ref()],
+        bump = creator_bond.bump,
+        close = creator,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
     pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = creator)]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<ClaimCreatorFee>) -> Result<()> {
     let market = &ctx.accounts.market;
     let config = &ctx.accounts.config;
 
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
+
     // Allow claim on Resolved OR Voided markets
     require!(
         market.status == MarketStatus::Resolved || market.status == MarketStatus::Voided,
@@ -43,10 +81,8 @@ pub fn handler(ctx: Context<ClaimCreatorFee>) -> Result<()> {
     );
     require!(!market.creator_fee_claimed, DegenBetsError::CreatorFeeAlreadyClaimed);
 
-    let total_payout;
-
-    if market.status == MarketStatus::Resolved {
-        // --- Resolved: creator fee + LP value (existing logic) ---
+    let creator_fee = if market.status == MarketStatus::Resolved {
+        // --- Resolved: creator fee ---
 
         // Security: challenge period must have passed
         let clock = Clock::get()?;
@@ -55,63 +91,53 @@ pub fn handler(ctx: Context<ClaimCreatorFee>) -> Result<()> {
             DegenBetsError::ChallengePeriodActive
         );
 
-        let total_pot = market.total_minted;
-        let total_rake = market.treasury_fee
-            .checked_add(market.creator_fee)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        let prize_pool = total_pot
-            .checked_sub(total_rake)
-            .ok_or(DegenBetsError::MathOverflow)?;
-
-        let outcome = market.outcome.unwrap();
-        let winning_reserve = if outcome { market.yes_reserve } else { market.no_reserve };
-        let lp_value = if total_pot > 0 {
-            (winning_reserve as u128)
-                .checked_mul(prize_pool as u128)
-                .ok_or(DegenBetsError::MathOverflow)?
-                .checked_div(total_pot as u128)
-                .ok_or(DegenBetsError::MathOverflow)? as u64
-        } else {
-            0
-        };
-
-        total_payout = market.creator_fee
-            .checked_add(lp_value)
-            .ok_or(DegenBetsError::MathOverflow)?;
+        market.creator_fee
     } else {
-        // --- Voided: return initial liquidity to creator ---
-        total_payout = market.initial_liquidity;
-    }
+        // --- Voided: no fee, only the creator's liquidity is returned ---
+        0
+    };
 
-    if total_payout > 0 {
-        // Rent-exemption guard
-        let rent = Rent::get()?;
-        let min_balance = rent.minimum_balance(Market::SIZE);
-        let market_lamports = ctx.accounts.market.to_account_info().lamports();
-        require!(
-            market_lamports.checked_sub(total_payout).unwrap_or(0) >= min_balance,
-            DegenBetsError::InsufficientRentBalance
-        );
+    // LP value: the creator's pro-rata share of the pool, like any other LP
+    let lp_shares = ctx.accounts.creator_liquidity.lp_shares;
+    let lp_value = claim_liquidity::settle_lp_shares(&mut ctx.accounts.market, lp_shares)?;
+    ctx.accounts.creator_liquidity.lp_shares = 0;
+
+    let total_payout = creator_fee
+        .checked_add(lp_value)
+        .ok_or(DegenBetsError::MathOverflow)?;
 
-        // Transfer from market PDA to creator (checked arithmetic)
-        let market_info = ctx.accounts.market.to_account_info();
-        let mut market_lamps = market_info.try_borrow_mut_lamports()?;
-        let creator_info = ctx.accounts.creator.to_account_info();
-        let mut creator_lamps = creator_info.try_borrow_mut_lamports()?;
-        **market_lamps = market_lamps
-            .checked_sub(total_payout)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        **creator_lamps = creator_lamps
-            .checked_add(total_payout)
-            .ok_or(DegenBetsError::MathOverflow)?;
+    if total_payout > 0 {
+        // Transfer collateral from vault to creator
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.creator_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.creator.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            total_payout,
+        )?;
     }
 
+    // The creator bond (already slashed on a creator-fault void) comes back
+    // when its escrow account closes
+    let bond_returned = ctx.accounts.market.creator_bond;
+
     // Update market
     let market = &mut ctx.accounts.market;
     market.creator_fee_claimed = true;
+    market.creator_bond = 0;
 
-    // Update creator profile
+    // Update creator profile; a resolution that survived the challenge
+    // period without being overturned earns reputation
     let profile = &mut ctx.accounts.creator_profile;
+    if market.status == MarketStatus::Resolved && !market.resolution_overturned {
+        profile.record_clean_resolution();
+    }
     profile.total_fees_earned = profile.total_fees_earned
         .checked_add(total_payout)
         .ok_or(DegenBetsError::MathOverflow)?;
@@ -120,6 +146,7 @@ pub fn handler(ctx: Context<ClaimCreatorFee>) -> Result<()> {
         market: ctx.accounts.market.key(),
         creator: ctx.accounts.creator.key(),
         amount: total_payout,
+        bond_returned,
     });
 
     Ok(())
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::LiquidityClaimed;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct ClaimLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The creator's position is settled by `claim_creator_fee`, which also
+    /// returns the fee and bond
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.creator != provider.key() @ DegenBetsError::CreatorLiquidityViaFeeClaim,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"liquidity", market.key().as_ref(), provider.key().as_ref()],
+        bump = liquidity_position.bump,
+        constraint = liquidity_position.market == market.key(),
+        constraint = liquidity_position.provider == provider.key(),
+        close = provider,
+    )]
+    pub liquidity_position: Account<'info, LiquidityPosition>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = provider)]
+    pub provider_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Settle `lp_shares` of a Resolved or Voided market's pool into SOL, burning
+/// the LP shares and the reserve shares backing them.
+///
+/// Resolved: the winning reserve shares pay out like any winning position.
+/// Voided: the reserve shares are refunded like any position, at the
+/// prices snapshotted on void.
+pub(crate) fn settle_lp_shares(market: &mut Market, lp_shares: u64) -> Result<u64> {
+    if lp_shares == 0 {
+        return Ok(0);
+    }
+    if market.market_type == MarketType::Categorical {
+        return settle_outcome_lp_shares(market, lp_shares);
+    }
+
+    let (yes_out, no_out) = math::calc_remove_liquidity(
+        lp_shares,
+        market.yes_reserve,
+        market.no_reserve,
+        market.total_lp_shares,
+    )?;
+
+    let amount = match market.status {
+        MarketStatus::Resolved => {
+            let winning_shares = market.resolved_winning_shares(yes_out, no_out)?;
+            math::calc_winning_payout(
+                winning_shares,
+                market.total_minted,
+                market.treasury_fee,
+                market.creator_fee,
+            )?
+        }
+        MarketStatus::Voided => {
+            let refund = market.binary_void_refund(yes_out, no_out)?;
+            market.record_void_refund(refund, &[yes_out, no_out])?;
+            refund
+        }
+        _ => return err!(DegenBetsError::MarketNotResolved),
+    };
+
+    market.yes_reserve = market.yes_reserve
+        .checked_sub(yes_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.no_reserve = market.no_reserve
+        .checked_sub(no_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.total_lp_shares = market.total_lp_shares
+        .checked_sub(lp_shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    Ok(amount)
+}
+
+/// Categorical variant of `settle_lp_shares`: the LP takes its pro-rata cut
+/// of every outcome reserve.
+fn settle_outcome_lp_shares(market: &mut Market, lp_shares: u64) -> Result<u64> {
+    let mut outs = Vec::with_capacity(market.outcome_reserves.len());
+    for reserve in market.outcome_reserves.iter_mut() {
+        let out = ((*reserve as u128)
+            .checked_mul(lp_shares as u128)
+            .ok_or(DegenBetsError::MathOverflow)?
+            / market.total_lp_shares as u128) as u64;
+        *reserve -= out;
+        outs.push(out);
+    }
+
+    let amount = match market.status {
+        MarketStatus::Resolved => {
+            let winning_outcome = market.winning_outcome.ok_or(DegenBetsError::MarketNotResolved)?;
+            math::calc_winning_payout(
+                outs[winning_outcome as usize],
+                market.total_minted,
+                market.treasury_fee,
+                market.creator_fee,
+            )?
+        }
+        MarketStatus::Voided => {
+            let refund = math::calc_void_refund(&outs, &market.void_prices)?;
+            market.record_void_refund(refund, &outs)?;
+            refund
+        }
+        _ => return err!(DegenBetsError::MarketNotResolved),
+    };
+
+    market.total_lp_shares = market.total_lp_shares
+        .checked_sub(lp_shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    Ok(amount)
+}
+
+pub fn handler(ctx: Context<ClaimLiquidity>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
+
+    require!(
+        market.status == MarketStatus::Resolved || market.status == MarketStatus::Voided,
+        DegenBetsError::MarketNotResolved
+    );
+
+    if market.status == MarketStatus::Resolved {
+        // Security: challenge period must have passed
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp >= market.resolved_at + config.challenge_period_seconds,
+            DegenBetsError::ChallengePeriodActive
+        );
+    }
+
+    let lp_shares = ctx.accounts.liquidity_position.lp_shares;
+    let market = &mut ctx.accounts.market;
+    let amount = settle_lp_shares(market, lp_shares)?;
+
+    if amount > 0 {
+        // Transfer collateral from vault to provider
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.provider_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.provider.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            amount,
+        )?;
+    }
+
+    ctx.accounts.liquidity_position.lp_shares = 0;
+
+    emit!(LiquidityClaimed {
+        market: ctx.accounts.market.key(),
+        provider: ctx.accounts.provider.key(),
+        lp_shares_burned: lp_shares,
+        amount,
+    });
+
+    Ok(())
+}
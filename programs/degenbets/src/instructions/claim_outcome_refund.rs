@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::RefundClaimed;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct ClaimOutcomeRefund<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type == MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.market == market.key(),
+        constraint = position.user == user.key(),
+    )]
+    pub position: Account<'info, OutcomePosition>,
+
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Refund a categorical position in a voided market.
+pub fn handler(ctx: Context<ClaimOutcomeRefund>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let position = &ctx.accounts.position;
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
+
+    require!(market.status == MarketStatus::Voided, DegenBetsError::MarketNotVoided);
+    require!(!position.claimed, DegenBetsError::AlreadyClaimed);
+
+    // Void refund: each share is worth its outcome's pool price when the
+    // market was voided, so a complete set is still worth 1 SOL
+    let refund_amount = math::calc_void_refund(&position.shares, &market.void_prices)?;
+    ctx.accounts.market.record_void_refund(refund_amount, &position.shares)?;
+
+    if refund_amount > 0 {
+        // Transfer collateral from vault to user
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.user.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            refund_amount,
+        )?;
+    }
+
+    // A void is neither a win nor a loss; only its PnL counts
+    let position = &mut ctx.accounts.position;
+    let pnl_before = position.realized_pnl;
+    position.record_claim(refund_amount)?;
+    position.claimed = true;
+
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+
+    emit!(RefundClaimed {
+        market: ctx.accounts.market.key(),
+        user: ctx.accounts.user.key(),
+        amount: refund_amount,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
+    });
+
+    Ok(())
+}
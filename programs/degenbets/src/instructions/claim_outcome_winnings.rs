@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::WinningsClaimed;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct ClaimOutcomeWinnings<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type == MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.market == market.key(),
+        constraint = position.user == user.key(),
+    )]
+    pub position: Account<'info, OutcomePosition>,
+
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Claim a categorical position's winning-outcome shares after resolution.
+pub fn handler(ctx: Context<ClaimOutcomeWinnings>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let position = &ctx.accounts.position;
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
+
+    require!(market.status == MarketStatus::Resolved, DegenBetsError::MarketNotResolved);
+    require!(!position.claimed, DegenBetsError::AlreadyClaimed);
+
+    // Security: challenge period must have passed
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= market.resolved_at + config.challenge_period_seconds,
+        DegenBetsError::ChallengePeriodActive
+    );
+
+    // Only the winning outcome pays out, every other outcome is worthless.
+    // Losing positions can still claim nothing to record the loss on their profile.
+    let winning_outcome = market.winning_outcome.ok_or(DegenBetsError::MarketNotResolved)?;
+    let user_winning_shares = position.shares[winning_outcome as usize];
+
+    // AMM payout: each winning share gets prize_pool / total_minted SOL
+    let user_share = math::calc_winning_payout(
+        user_winning_shares,
+        market.total_minted,
+        market.treasury_fee,
+        market.creator_fee,
+    )?;
+
+    if user_share > 0 {
+        // Transfer collateral from vault to user
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.user.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            user_share,
+        )?;
+    }
+
+    // Mark claimed; the payout closes out every share held
+    let position = &mut ctx.accounts.position;
+    let pnl_before = position.realized_pnl;
+    position.record_claim(user_share)?;
+    position.claimed = true;
+
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+    trader.record_settlement(position.realized_pnl);
+
+    emit!(WinningsClaimed {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount: user_share,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
+    });
+
+    Ok(())
+}
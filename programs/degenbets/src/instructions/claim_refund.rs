@@ -1,20 +1,37 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::RefundClaimed;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct ClaimRefund<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
@@ -24,53 +41,78 @@ pub struct ClaimRefund<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
     pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
     let market = &ctx.accounts.market;
     let position = &ctx.accounts.position;
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
 
     require!(market.status == MarketStatus::Voided, DegenBetsError::MarketNotVoided);
     require!(!position.claimed, DegenBetsError::AlreadyClaimed);
 
-    // AMM void refund: each share (YES or NO) is worth 0.5 SOL equivalent
-    // total_shares / 2 = SOL refund
-    let total_shares = position.yes_shares
-        .checked_add(position.no_shares)
-        .ok_or(DegenBetsError::MathOverflow)?;
-    let refund_amount = total_shares / 2;
+    // Void refund: each share is worth its side's pool price when the market
+    // was voided, so a complete YES+NO set is still worth 1 SOL
+    let refund_amount = market.binary_void_refund(position.yes_shares, position.no_shares)?;
+    ctx.accounts.market.record_void_refund(refund_amount, &[position.yes_shares, position.no_shares])?;
 
     if refund_amount > 0 {
-        // Rent-exemption guard
-        let rent = Rent::get()?;
-        let min_balance = rent.minimum_balance(Market::SIZE);
-        let market_lamports = ctx.accounts.market.to_account_info().lamports();
-        require!(
-            market_lamports.checked_sub(refund_amount).unwrap_or(0) >= min_balance,
-            DegenBetsError::InsufficientRentBalance
-        );
-
-        // Checked arithmetic transfer
-        let market_info = ctx.accounts.market.to_account_info();
-        let mut market_lamps = market_info.try_borrow_mut_lamports()?;
-        let user_info = ctx.accounts.user.to_account_info();
-        let mut user_lamps = user_info.try_borrow_mut_lamports()?;
-        **market_lamps = market_lamps
-            .checked_sub(refund_amount)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        **user_lamps = user_lamps
-            .checked_add(refund_amount)
-            .ok_or(DegenBetsError::MathOverflow)?;
+        // Transfer collateral from vault to user
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.user.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            refund_amount,
+        )?;
     }
 
+    // A void is neither a win nor a loss; only its PnL counts
     let position = &mut ctx.accounts.position;
+    let pnl_before = position.realized_pnl;
+    position.record_claim(refund_amount)?;
     position.claimed = true;
 
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+
     emit!(RefundClaimed {
         market: ctx.accounts.market.key(),
         user: ctx.accounts.user.key(),
         amount: refund_amount,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
     });
 
     Ok(())
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::TreasuryFeeClaimed;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct ClaimTreasuryFee<'info> {
     #[account(
-        constraint = authority.key() == config.authority,
+        constraint = config.has_role(AdminRole::TreasuryClaimer, &authority.key()) @ DegenBetsError::MissingRole,
     )]
     pub authority: Signer<'info>,
 
@@ -23,12 +25,27 @@ pub struct ClaimTreasuryFee<'info> {
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
     /// Treasury wallet to receive the fee
     #[account(
         mut,
         constraint = treasury.key() == config.treasury,
     )]
     pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = config.treasury)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<ClaimTreasuryFee>) -> Result<()> {
@@ -58,26 +75,20 @@ pub fn handler(ctx: Context<ClaimTreasuryFee>) -> Result<()> {
     };
 
     if payout > 0 {
-        // Rent-exemption guard
-        let rent = Rent::get()?;
-        let min_balance = rent.minimum_balance(Market::SIZE);
-        let market_lamports = ctx.accounts.market.to_account_info().lamports();
-        require!(
-            market_lamports.checked_sub(payout).unwrap_or(0) >= min_balance,
-            DegenBetsError::InsufficientRentBalance
-        );
-
-        // Transfer from market PDA to treasury (checked arithmetic)
-        let market_info = ctx.accounts.market.to_account_info();
-        let mut market_lamps = market_info.try_borrow_mut_lamports()?;
-        let treasury_info = ctx.accounts.treasury.to_account_info();
-        let mut treasury_lamps = treasury_info.try_borrow_mut_lamports()?;
-        **market_lamps = market_lamps
-            .checked_sub(payout)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        **treasury_lamps = treasury_lamps
-            .checked_add(payout)
-            .ok_or(DegenBetsError::MathOverflow)?;
+        // Transfer collateral from vault to treasury
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.treasury_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.treasury.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            payout,
+        )?;
     }
 
     // Mark claimed
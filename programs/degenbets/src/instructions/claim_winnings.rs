@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::WinningsClaimed;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct ClaimWinnings<'info> {
@@ -18,9 +21,18 @@ pub struct ClaimWinnings<'info> {
         mut,
         seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
@@ -30,7 +42,22 @@ pub struct ClaimWinnings<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
     pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
@@ -38,6 +65,9 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     let position = &ctx.accounts.position;
     let config = &ctx.accounts.config;
 
+    require!(!config.is_paused(Config::PAUSE_CLAIMS), DegenBetsError::PlatformPaused);
+    require!(!market.paused, DegenBetsError::MarketPaused);
+
     require!(market.status == MarketStatus::Resolved, DegenBetsError::MarketNotResolved);
     require!(!position.claimed, DegenBetsError::AlreadyClaimed);
 
@@ -48,62 +78,58 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
         DegenBetsError::ChallengePeriodActive
     );
 
-    let outcome = market.outcome.unwrap();
-    let user_winning_shares = if outcome {
-        position.yes_shares
-    } else {
-        position.no_shares
-    };
-    require!(user_winning_shares > 0, DegenBetsError::NotAWinner);
+    // Mixed positions: only the winning side pays out, losing shares are worthless
+    // (scalar: LONG and SHORT each take their fraction of every set). Losing
+    // positions can still claim nothing to record the loss on their profile.
+    let user_winning_shares = market.resolved_winning_shares(position.yes_shares, position.no_shares)?;
 
     // AMM payout: each winning share gets prize_pool / total_minted SOL
-    let total_pot = market.total_minted;
-    let total_rake = market.treasury_fee
-        .checked_add(market.creator_fee)
-        .ok_or(DegenBetsError::MathOverflow)?;
-
-    let prize_pool = total_pot
-        .checked_sub(total_rake)
-        .ok_or(DegenBetsError::MathOverflow)?;
-
-    // user_share = (user_winning_shares / total_minted) * prize_pool
-    let user_share = (user_winning_shares as u128)
-        .checked_mul(prize_pool as u128)
-        .ok_or(DegenBetsError::MathOverflow)?
-        .checked_div(total_pot as u128)
-        .ok_or(DegenBetsError::MathOverflow)? as u64;
-
-    // Rent-exemption guard
-    let rent = Rent::get()?;
-    let min_balance = rent.minimum_balance(Market::SIZE);
-    let market_lamports = ctx.accounts.market.to_account_info().lamports();
-    require!(
-        market_lamports.checked_sub(user_share).unwrap_or(0) >= min_balance,
-        DegenBetsError::InsufficientRentBalance
-    );
-
-    // Transfer from market PDA to user (checked arithmetic)
-    {
-        let market_info = ctx.accounts.market.to_account_info();
-        let mut market_lamps = market_info.try_borrow_mut_lamports()?;
-        let user_info = ctx.accounts.user.to_account_info();
-        let mut user_lamps = user_info.try_borrow_mut_lamports()?;
-        **market_lamps = market_lamps
-            .checked_sub(user_share)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        **user_lamps = user_lamps
-            .checked_add(user_share)
-            .ok_or(DegenBetsError::MathOverflow)?;
+    let user_share = math::calc_winning_payout(
+        user_winning_shares,
+        market.total_minted,
+        market.treasury_fee,
+        market.creator_fee,
+    )?;
+
+    if user_share > 0 {
+        // Transfer collateral from vault to user
+        let token = TokenCollateral::resolve(
+            &ctx.accounts.market,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        collateral::withdraw(
+            &ctx.accounts.market,
+            &ctx.accounts.vault,
+            &ctx.accounts.user.to_account_info(),
+            token.as_ref(),
+            &ctx.accounts.system_program,
+            user_share,
+        )?;
     }
 
-    // Mark claimed
+    // Mark claimed; the payout closes out every share held
     let position = &mut ctx.accounts.position;
+    let pnl_before = position.realized_pnl;
+    position.record_claim(user_share)?;
     position.claimed = true;
 
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+    trader.record_settlement(position.realized_pnl);
+
     emit!(WinningsClaimed {
         market: market.key(),
         user: ctx.accounts.user.key(),
         amount: user_share,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
     });
 
     Ok(())
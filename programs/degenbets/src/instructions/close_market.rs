@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
+use crate::collateral::{self, TokenCollateral};
+
+/// How long after a void refund holders and LPs have to claim before the
+/// vault can be swept regardless
+const VOID_CLAIM_PERIOD: i64 = 90 * 24 * 3600; // 90 days
 
 #[derive(Accounts)]
 pub struct CloseMarket<'info> {
@@ -23,6 +29,21 @@ pub struct CloseMarket<'info> {
         close = authority,
     )]
     pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = authority)]
+    pub authority_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
@@ -35,6 +56,37 @@ pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
             && market.treasury_fee_claimed);
 
     require!(can_close, DegenBetsError::MarketNotCloseable);
+    require!(market.creator_bond == 0, DegenBetsError::CreatorBondOutstanding);
+
+    // Resolved pools must be fully settled to their LPs before the sweep
+    if market.status == MarketStatus::Resolved {
+        require!(market.total_lp_shares == 0, DegenBetsError::LiquidityOutstanding);
+    }
+
+    // Voided markets wait until every refund and LP share is settled, or
+    // until the claim period has run out
+    if market.status == MarketStatus::Voided {
+        let settled = market.total_lp_shares == 0 && market.void_settled();
+        let clock = Clock::get()?;
+        require!(
+            settled || clock.unix_timestamp >= market.resolved_at + VOID_CLAIM_PERIOD,
+            DegenBetsError::VoidClaimsOutstanding
+        );
+    }
+
+    // Sweep leftover vault dust to authority and close the vault
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.authority_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::close_vault(
+        market,
+        &ctx.accounts.vault,
+        &ctx.accounts.authority.to_account_info(),
+        token.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+
+#[derive(Accounts)]
+pub struct CloseOutcomePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == user.key(),
+        constraint = position.claimed @ DegenBetsError::AlreadyClaimed,
+        close = user,
+    )]
+    pub position: Account<'info, OutcomePosition>,
+}
+
+/// Categorical counterpart of `close_position`: reclaim the rent of a
+/// claimed outcome position once its market has settled.
+pub fn handler(ctx: Context<CloseOutcomePosition>) -> Result<()> {
+    let market = &ctx.accounts.market;
+
+    require!(
+        market.status == MarketStatus::Resolved || market.status == MarketStatus::Voided,
+        DegenBetsError::MarketNotResolved
+    );
+
+    Ok(())
+}
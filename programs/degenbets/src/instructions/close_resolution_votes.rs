@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+
+#[derive(Accounts)]
+pub struct CloseResolutionVotes<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"votes", market.key().as_ref()],
+        bump = votes.bump,
+        has_one = market,
+        has_one = payer,
+        close = payer,
+    )]
+    pub votes: Account<'info, ResolutionVotes>,
+}
+
+/// Return the rent of a market's committee votes to the member who opened
+/// them, once voting is over (the market is no longer open).
+pub fn handler(ctx: Context<CloseResolutionVotes>) -> Result<()> {
+    require!(
+        ctx.accounts.market.status != MarketStatus::Open,
+        DegenBetsError::MarketNotResolved
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use super::create_market::{self, NewMarket};
+
+#[derive(Accounts)]
+#[instruction(
+    question: String,
+    resolution_source: String,
+    resolution_timestamp: i64,
+    liquidity_amount: u64,
+    outcomes: Vec<String>,
+)]
+pub struct CreateCategoricalMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Market::space_for(outcomes.len()),
+        seeds = [b"market", config.market_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA, created in the handler (system account for
+    /// SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = LiquidityPosition::SIZE,
+        seeds = [b"liquidity", market.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_liquidity: Account<'info, LiquidityPosition>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorProfile::SIZE,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorBond::SIZE,
+        seeds = [b"bond", market.key().as_ref()],
+        bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL collateral mint, token markets only (omit for SOL markets)
+    pub collateral_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::authority = creator)]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Create a market with 3-16 named outcomes sharing one N-outcome pool.
+pub fn handler(
+    ctx: Context<CreateCategoricalMarket>,
+    question: String,
+    resolution_source: String,
+    resolution_timestamp: i64,
+    liquidity_amount: u64,
+    outcomes: Vec<String>,
+    resolver: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        outcomes.len() >= Market::MIN_OUTCOMES && outcomes.len() <= Market::MAX_OUTCOMES,
+        DegenBetsError::InvalidOutcomeCount
+    );
+    require!(
+        outcomes.iter().all(|label| label.len() <= Market::MAX_OUTCOME_LABEL_LEN),
+        DegenBetsError::OutcomeLabelTooLong
+    );
+
+    let accounts = ctx.accounts;
+    create_market::init_market_common(
+        NewMarket {
+            creator: &accounts.creator,
+            config: &mut accounts.config,
+            market: &mut accounts.market,
+            vault: &accounts.vault,
+            creator_liquidity: &mut accounts.creator_liquidity,
+            creator_profile: &mut accounts.creator_profile,
+            creator_bond: &mut accounts.creator_bond,
+            system_program: &accounts.system_program,
+            collateral_mint: &accounts.collateral_mint,
+            creator_token_account: &accounts.creator_token_account,
+            token_program: &accounts.token_program,
+            market_bump: ctx.bumps.market,
+            vault_bump: ctx.bumps.vault,
+            creator_liquidity_bump: ctx.bumps.creator_liquidity,
+            creator_profile_bump: ctx.bumps.creator_profile,
+            creator_bond_bump: ctx.bumps.creator_bond,
+        },
+        question,
+        resolution_source,
+        resolution_timestamp,
+        liquidity_amount,
+        MarketType::Categorical,
+        outcomes,
+        0,
+        0,
+        None,
+        resolver,
+    )
+}
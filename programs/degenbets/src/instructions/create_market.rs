@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::MarketCreated;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct CreateMarket<'info> {
@@ -25,6 +27,24 @@ pub struct CreateMarket<'info> {
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA, created in the handler (system account for
+    /// SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = LiquidityPosition::SIZE,
+        seeds = [b"liquidity", market.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_liquidity: Account<'info, LiquidityPosition>,
+
     #[account(
         init_if_needed,
         payer = creator,
@@ -34,7 +54,24 @@ pub struct CreateMarket<'info> {
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorBond::SIZE,
+        seeds = [b"bond", market.key().as_ref()],
+        bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
     pub system_program: Program<'info, System>,
+
+    /// SPL collateral mint, token markets only (omit for SOL markets)
+    pub collateral_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::authority = creator)]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(
@@ -43,9 +80,129 @@ pub fn handler(
     resolution_source: String,
     resolution_timestamp: i64,
     liquidity_amount: u64,
+    oracle: Option<OracleCondition>,
+    resolver: Option<Pubkey>,
 ) -> Result<()> {
+    create(
+        ctx,
+        question,
+        resolution_source,
+        resolution_timestamp,
+        liquidity_amount,
+        MarketType::Binary,
+        0,
+        0,
+        oracle,
+        resolver,
+    )
+}
+
+/// Shared by binary and scalar markets, which trade on the same YES/NO pool
+/// (scalar LONG = YES, SHORT = NO).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create(
+    ctx: Context<CreateMarket>,
+    question: String,
+    resolution_source: String,
+    resolution_timestamp: i64,
+    liquidity_amount: u64,
+    market_type: MarketType,
+    lower_bound: i64,
+    upper_bound: i64,
+    oracle: Option<OracleCondition>,
+    resolver: Option<Pubkey>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    init_market_common(
+        NewMarket {
+            creator: &accounts.creator,
+            config: &mut accounts.config,
+            market: &mut accounts.market,
+            vault: &accounts.vault,
+            creator_liquidity: &mut accounts.creator_liquidity,
+            creator_profile: &mut accounts.creator_profile,
+            creator_bond: &mut accounts.creator_bond,
+            system_program: &accounts.system_program,
+            collateral_mint: &accounts.collateral_mint,
+            creator_token_account: &accounts.creator_token_account,
+            token_program: &accounts.token_program,
+            market_bump: ctx.bumps.market,
+            vault_bump: ctx.bumps.vault,
+            creator_liquidity_bump: ctx.bumps.creator_liquidity,
+            creator_profile_bump: ctx.bumps.creator_profile,
+            creator_bond_bump: ctx.bumps.creator_bond,
+        },
+        question,
+        resolution_source,
+        resolution_timestamp,
+        liquidity_amount,
+        market_type,
+        Vec::new(),
+        lower_bound,
+        upper_bound,
+        oracle,
+        resolver,
+    )
+}
+
+/// Accounts and bumps every market creation instruction shares.
+pub(crate) struct NewMarket<'a, 'info> {
+    pub creator: &'a Signer<'info>,
+    pub config: &'a mut Account<'info, Config>,
+    pub market: &'a mut Account<'info, Market>,
+    pub vault: &'a UncheckedAccount<'info>,
+    pub creator_liquidity: &'a mut Account<'info, LiquidityPosition>,
+    pub creator_profile: &'a mut Account<'info, CreatorProfile>,
+    pub creator_bond: &'a mut Account<'info, CreatorBond>,
+    pub system_program: &'a Program<'info, System>,
+    pub collateral_mint: &'a Option<Account<'info, Mint>>,
+    pub creator_token_account: &'a Option<Account<'info, TokenAccount>>,
+    pub token_program: &'a Option<Program<'info, Token>>,
+    pub market_bump: u8,
+    pub vault_bump: u8,
+    pub creator_liquidity_bump: u8,
+    pub creator_profile_bump: u8,
+    pub creator_bond_bump: u8,
+}
+
+/// Validate and initialize a new market of any type, fund its pool with the
+/// creator's liquidity and escrow the creator bond. Categorical markets pass
+/// their `outcomes` labels; binary and scalar markets pass none.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn init_market_common(
+    accounts: NewMarket,
+    question: String,
+    resolution_source: String,
+    resolution_timestamp: i64,
+    liquidity_amount: u64,
+    market_type: MarketType,
+    outcomes: Vec<String>,
+    lower_bound: i64,
+    upper_bound: i64,
+    oracle: Option<OracleCondition>,
+    resolver: Option<Pubkey>,
+) -> Result<()> {
+    let NewMarket {
+        creator,
+        config,
+        market,
+        vault,
+        creator_liquidity,
+        creator_profile: profile,
+        creator_bond: bond,
+        system_program,
+        collateral_mint: mint,
+        creator_token_account,
+        token_program,
+        market_bump,
+        vault_bump,
+        creator_liquidity_bump,
+        creator_profile_bump,
+        creator_bond_bump,
+    } = accounts;
+
     // Security: platform pause check
-    require!(!ctx.accounts.config.paused, DegenBetsError::PlatformPaused);
+    require!(!config.is_paused(Config::PAUSE_CREATION), DegenBetsError::PlatformPaused);
 
     require!(question.len() <= Market::MAX_QUESTION_LEN, DegenBetsError::QuestionTooLong);
     require!(resolution_source.len() <= Market::MAX_SOURCE_LEN, DegenBetsError::SourceTooLong);
@@ -62,33 +219,40 @@ pub fn handler(
         DegenBetsError::ResolutionTooSoon
     );
 
-    // Validate minimum liquidity
+    if let Some(condition) = &oracle {
+        require!(condition.feed != Pubkey::default(), DegenBetsError::InvalidOracleAccount);
+    }
+
+    // Validate minimum liquidity (per collateral mint; SOL if none given),
+    // discounted for high-reputation creators
+    let collateral_mint = mint
+        .as_ref()
+        .map(|mint| mint.key())
+        .unwrap_or_default();
     require!(
-        liquidity_amount >= ctx.accounts.config.min_liquidity_lamports,
+        liquidity_amount >= profile.min_liquidity(config.min_liquidity_for(&collateral_mint)?),
         DegenBetsError::InsufficientLiquidity
     );
 
-    // Transfer liquidity SOL from creator to market PDA
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.creator.to_account_info(),
-            to: ctx.accounts.market.to_account_info(),
-        },
-    );
-    system_program::transfer(cpi_ctx, liquidity_amount)?;
-
     // Initialize market
-    let market = &mut ctx.accounts.market;
-    let config = &mut ctx.accounts.config;
-
-    market.creator = ctx.accounts.creator.key();
+    market.collateral_mint = collateral_mint;
+    market.vault_bump = vault_bump;
+    market.creator = creator.key();
     market.question = question.clone();
     market.resolution_source = resolution_source.clone();
 
-    // AMM: initialize 50/50 pool with creator's liquidity
-    market.yes_reserve = liquidity_amount;
-    market.no_reserve = liquidity_amount;
+    // AMM: the creator's liquidity mints complete sets, all held by the pool,
+    // so every outcome starts at 1/N (50/50 for YES/NO pools)
+    if market_type == MarketType::Categorical {
+        market.yes_reserve = 0;
+        market.no_reserve = 0;
+        market.outcome_reserves = vec![liquidity_amount; outcomes.len()];
+    } else {
+        market.yes_reserve = liquidity_amount;
+        market.no_reserve = liquidity_amount;
+        market.outcome_reserves = Vec::new();
+    }
+    market.outcome_labels = outcomes.clone();
     market.total_minted = liquidity_amount;
     market.initial_liquidity = liquidity_amount;
     market.swap_fee_bps = config.swap_fee_bps;
@@ -100,18 +264,39 @@ pub fn handler(
     market.treasury_fee_claimed = false;
     market.market_id = config.market_count;
     market.resolved_at = 0;
-    market.bump = ctx.bumps.market;
+    market.bump = market_bump;
     market.treasury_fee = 0;
     market.creator_fee = 0;
     market.treasury_rake_bps = config.treasury_rake_bps;
-    market.creator_rake_bps = config.creator_rake_bps;
+    market.creator_rake_bps = profile.creator_rake_bps(config);
+    market.total_lp_shares = liquidity_amount;
+    market.market_type = market_type;
+    market.winning_outcome = None;
+    market.lower_bound = lower_bound;
+    market.upper_bound = upper_bound;
+    market.resolved_value = None;
+    market.oracle = oracle;
+    market.resolver = resolver;
+    market.resolved_by = Pubkey::default();
+    market.paused = false;
+    market.creator_bond = config.creator_bond_lamports;
+    market.void_reason = None;
+    market.void_prices = Vec::new();
+    market.void_refunded = 0;
+    market.void_shares_refunded = 0;
+    market.resolution_overturned = false;
+
+    // Creator is the first LP, holding all pool shares until others join
+    creator_liquidity.market = market.key();
+    creator_liquidity.provider = creator.key();
+    creator_liquidity.lp_shares = liquidity_amount;
+    creator_liquidity.bump = creator_liquidity_bump;
 
     // Update creator profile
-    let profile = &mut ctx.accounts.creator_profile;
     if profile.markets_created == 0 && profile.wallet == Pubkey::default() {
-        profile.wallet = ctx.accounts.creator.key();
-        profile.reputation_score = 100;
-        profile.bump = ctx.bumps.creator_profile;
+        profile.wallet = creator.key();
+        profile.reputation_score = CreatorProfile::INITIAL_REPUTATION;
+        profile.bump = creator_profile_bump;
     }
     profile.markets_created += 1;
 
@@ -120,14 +305,53 @@ pub fn handler(
         .checked_add(1)
         .ok_or(DegenBetsError::MathOverflow)?;
 
+    // Create the vault, then transfer liquidity from creator into it
+    let token = TokenCollateral::resolve(market, creator_token_account, token_program)?;
+    collateral::init_vault(
+        market,
+        vault,
+        creator,
+        mint.as_ref(),
+        token.as_ref(),
+        system_program,
+    )?;
+    collateral::deposit(
+        vault,
+        creator,
+        token.as_ref(),
+        system_program,
+        liquidity_amount,
+    )?;
+
+    // Escrow the creator bond in its own account, apart from the market's rent
+    bond.market = market.key();
+    bond.bump = creator_bond_bump;
+    if market.creator_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: bond.to_account_info(),
+                },
+            ),
+            market.creator_bond,
+        )?;
+    }
+
     emit!(MarketCreated {
         market: market.key(),
-        creator: ctx.accounts.creator.key(),
+        creator: creator.key(),
         question,
         resolution_source,
         resolution_timestamp,
         liquidity_amount,
         market_id: market.market_id,
+        collateral_mint,
+        outcomes,
+        lower_bound,
+        upper_bound,
+        resolver,
     });
 
     Ok(())
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::{MarketType, OracleCondition};
+use crate::errors::DegenBetsError;
+use super::create_market::{self, CreateMarket};
+
+/// Create a scalar market over `[lower_bound, upper_bound]`. It trades on the
+/// binary YES/NO pool, with YES as LONG and NO as SHORT. With an oracle
+/// condition the market resolves to the feed price.
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateMarket>,
+    question: String,
+    resolution_source: String,
+    resolution_timestamp: i64,
+    liquidity_amount: u64,
+    lower_bound: i64,
+    upper_bound: i64,
+    oracle: Option<OracleCondition>,
+    resolver: Option<Pubkey>,
+) -> Result<()> {
+    require!(upper_bound > lower_bound, DegenBetsError::InvalidScalarBounds);
+
+    create_market::create(
+        ctx,
+        question,
+        resolution_source,
+        resolution_timestamp,
+        liquidity_amount,
+        MarketType::Scalar,
+        lower_bound,
+        upper_bound,
+        oracle,
+        resolver,
+    )
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ResolutionDisputed;
+
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Disputer's position PDA, deserialized per market type in the handler
+    #[account(
+        seeds = [b"position", market.key().as_ref(), disputer.key().as_ref()],
+        bump,
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = disputer,
+        space = Dispute::SIZE,
+        seeds = [b"dispute", market.key().as_ref()],
+        bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Challenge a resolution inside the challenge period by posting a SOL bond.
+/// Freezes all claims until the authority settles the dispute.
+pub fn handler(ctx: Context<DisputeResolution>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let config = &ctx.accounts.config;
+
+    require!(market.status == MarketStatus::Resolved, DegenBetsError::MarketNotResolved);
+
+    // Only holders of the market can dispute
+    let data = ctx.accounts.position.try_borrow_data()?;
+    let holds_shares = match market.market_type {
+        MarketType::Categorical => {
+            let position = OutcomePosition::try_deserialize(&mut &data[..])?;
+            position.shares.iter().any(|&shares| shares > 0)
+        }
+        _ => {
+            let position = Position::try_deserialize(&mut &data[..])?;
+            position.yes_shares > 0 || position.no_shares > 0
+        }
+    };
+    drop(data);
+    require!(holds_shares, DegenBetsError::NoPositionHeld);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < market.resolved_at + config.challenge_period_seconds,
+        DegenBetsError::ChallengePeriodEnded
+    );
+
+    // Bond is held in the dispute account on top of its rent
+    let bond = config.dispute_bond_lamports;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.disputer.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.market = ctx.accounts.market.key();
+    dispute.disputer = ctx.accounts.disputer.key();
+    dispute.bond = bond;
+    dispute.disputed_at = clock.unix_timestamp;
+    dispute.bump = ctx.bumps.dispute;
+
+    let market = &mut ctx.accounts.market;
+    market.status = MarketStatus::Disputed;
+
+    emit!(ResolutionDisputed {
+        market: market.key(),
+        disputer: ctx.accounts.disputer.key(),
+        disputed_outcome: market.winning_outcome.unwrap_or_default(),
+        bond,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::{ConfigChangeExecuted, ConfigUpdated};
+use super::update_config;
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"config_change"],
+        bump = pending_change.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// Proposer receives the pending change's rent back
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+/// Permissionless: apply a queued config change after its eta.
+pub fn handler(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending = &ctx.accounts.pending_change;
+    require!(
+        Clock::get()?.unix_timestamp >= pending.eta,
+        DegenBetsError::TimelockNotElapsed
+    );
+
+    let old = pending.params.current_values(&ctx.accounts.config);
+    update_config::apply(&mut ctx.accounts.config, &pending.params)?;
+
+    emit!(ConfigChangeExecuted {
+        executor: ctx.accounts.executor.key(),
+        params: pending.params.clone(),
+    });
+    emit!(ConfigUpdated {
+        old,
+        new: pending.params.clone(),
+    });
+
+    Ok(())
+}
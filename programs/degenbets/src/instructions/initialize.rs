@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::Config;
 use crate::errors::DegenBetsError;
+use crate::events::ConfigInitialized;
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -19,6 +20,7 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<Initialize>,
     treasury: Pubkey,
@@ -50,12 +52,38 @@ pub fn handler(
     config.treasury_rake_bps = treasury_rake_bps;
     config.creator_rake_bps = creator_rake_bps;
     config.market_count = 0;
-    config.paused = false;
+    config.pause_flags = 0;
     config.min_trade_lamports = min_trade_lamports;
     config.betting_cutoff_seconds = betting_cutoff_seconds;
     config.challenge_period_seconds = challenge_period_seconds;
     config.swap_fee_bps = swap_fee_bps;
     config.bump = ctx.bumps.config;
+    config.collateral_mints = Vec::new();
+    config.dispute_bond_lamports = Config::DEFAULT_DISPUTE_BOND_LAMPORTS;
+    config.oracle_program = Pubkey::default();
+    config.pending_authority = None;
+    config.pauser = Pubkey::default();
+    config.resolver = Pubkey::default();
+    config.config_admin = Pubkey::default();
+    config.treasury_claimer = Pubkey::default();
+    config.config_timelock_seconds = Config::DEFAULT_CONFIG_TIMELOCK_SECONDS;
+    config.creator_bond_lamports = Config::DEFAULT_CREATOR_BOND_LAMPORTS;
+    config.fee_tiers = Vec::new();
+
+    emit!(ConfigInitialized {
+        authority: config.authority,
+        treasury,
+        min_liquidity_lamports,
+        treasury_rake_bps,
+        creator_rake_bps,
+        min_trade_lamports,
+        betting_cutoff_seconds,
+        challenge_period_seconds,
+        swap_fee_bps,
+        dispute_bond_lamports: config.dispute_bond_lamports,
+        config_timelock_seconds: config.config_timelock_seconds,
+        creator_bond_lamports: config.creator_bond_lamports,
+    });
 
     Ok(())
 }
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::SetsMerged;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct Merge<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.market == market.key(),
+        constraint = position.user == user.key(),
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Trading record, created on first use. Merges realize PnL but add no volume
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Burn matched YES+NO pairs as complete sets for collateral at 1:1, bypassing the AMM.
+pub fn handler(ctx: Context<Merge>, pairs: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    require!(pairs > 0, DegenBetsError::ZeroBetAmount);
+    require!(ctx.accounts.market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+
+    let position = &mut ctx.accounts.position;
+    require!(
+        position.yes_shares >= pairs && position.no_shares >= pairs,
+        DegenBetsError::InsufficientShares
+    );
+
+    // Each burned complete set releases exactly one unit of collateral
+    let market = &mut ctx.accounts.market;
+    market.total_minted = market.total_minted
+        .checked_sub(pairs)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Transfer collateral from vault to user
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::withdraw(
+        market,
+        &ctx.accounts.vault,
+        &ctx.accounts.user.to_account_info(),
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        pairs,
+    )?;
+
+    let pnl_before = position.realized_pnl;
+    position.record_proceeds(pairs, pairs, pairs)?;
+    position.yes_shares = position.yes_shares
+        .checked_sub(pairs)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    position.no_shares = position.no_shares
+        .checked_sub(pairs)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(market, &ctx.accounts.vault)?;
+
+    emit!(SetsMerged {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        pairs,
+        sol_received: pairs,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ConfigMigrated;
+use crate::migration;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy `Config`. It can't deserialize until resized, so
+    /// discriminator and size are checked in the handler
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        owner = crate::ID @ DegenBetsError::InvalidMigrationAccount,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow the config created before collateral mints, admin roles and fee tiers
+/// existed to the current layout. New fields start at the values `initialize`
+/// gives them: no roles assigned, no extra mints or tiers, and default bonds
+/// and timelock. Permissionless; the payer covers the extra rent.
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+
+    migration::check_legacy(
+        &info.try_borrow_data()?,
+        Config::DISCRIMINATOR,
+        Config::LEGACY_SIZE,
+        Config::SIZE,
+    )?;
+    migration::grow(&info, &ctx.accounts.payer, &ctx.accounts.system_program, Config::SIZE)?;
+
+    let mut config = Config::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    // The legacy `paused` bool stopped everything
+    if config.pause_flags != 0 {
+        config.pause_flags = Config::PAUSE_ALL;
+    }
+    config.dispute_bond_lamports = Config::DEFAULT_DISPUTE_BOND_LAMPORTS;
+    config.config_timelock_seconds = Config::DEFAULT_CONFIG_TIMELOCK_SECONDS;
+    config.creator_bond_lamports = Config::DEFAULT_CREATOR_BOND_LAMPORTS;
+    config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(ConfigMigrated {
+        config: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
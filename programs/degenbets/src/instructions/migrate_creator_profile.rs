@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::CreatorProfileMigrated;
+use crate::migration;
+
+#[derive(Accounts)]
+pub struct MigrateCreatorProfile<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy `CreatorProfile`. It can't deserialize until resized, so
+    /// owner, discriminator and size are checked in the handler
+    #[account(mut, owner = crate::ID @ DegenBetsError::InvalidMigrationAccount)]
+    pub creator_profile: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a creator profile created before `volume_bonus_awarded` existed to
+/// the current layout. Legacy scores never included volume points, so the
+/// field starts at zero and the creator's volume is credited at their next
+/// clean resolution. Permissionless; the payer covers the extra rent.
+pub fn handler(ctx: Context<MigrateCreatorProfile>) -> Result<()> {
+    let profile = ctx.accounts.creator_profile.to_account_info();
+
+    migration::check_legacy(
+        &profile.try_borrow_data()?,
+        CreatorProfile::DISCRIMINATOR,
+        CreatorProfile::LEGACY_SIZE,
+        CreatorProfile::SIZE,
+    )?;
+
+    // Appended bytes are zeroed: no volume points credited yet
+    migration::grow(
+        &profile,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        CreatorProfile::SIZE,
+    )?;
+
+    emit!(CreatorProfileMigrated {
+        creator_profile: profile.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::MarketMigrated;
+use crate::collateral;
+use crate::migration;
+
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy `Market`. It can't deserialize until resized, so
+    /// discriminator and size are checked in the handler
+    #[account(mut, owner = crate::ID @ DegenBetsError::InvalidMigrationAccount)]
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: SOL vault PDA, funded in the handler
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Market creator, checked against the market in the handler
+    pub creator: UncheckedAccount<'info>,
+
+    /// The creator's pool share, which legacy markets tracked implicitly
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPosition::SIZE,
+        seeds = [b"liquidity", market.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_liquidity: Account<'info, LiquidityPosition>,
+
+    /// Empty bond escrow; legacy creators posted no bond
+    #[account(
+        init,
+        payer = payer,
+        space = CreatorBond::SIZE,
+        seeds = [b"bond", market.key().as_ref()],
+        bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a market created before LP shares and collateral vaults existed to
+/// the current layout. Legacy markets are SOL markets holding their collateral
+/// in the market account itself, so it moves into a new vault, and the
+/// creator gets the liquidity position and bond escrow that `create_market`
+/// now sets up. Permissionless; the payer covers the new rent.
+pub fn handler(ctx: Context<MigrateMarket>) -> Result<()> {
+    let info = ctx.accounts.market.to_account_info();
+
+    migration::check_legacy(
+        &info.try_borrow_data()?,
+        Market::DISCRIMINATOR,
+        Market::LEGACY_SIZE,
+        Market::SIZE,
+    )?;
+
+    // Everything above the market's own rent is collateral
+    let rent = Rent::get()?;
+    let collateral = info.lamports().saturating_sub(rent.minimum_balance(Market::LEGACY_SIZE));
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        rent.minimum_balance(0),
+    )?;
+    collateral::move_lamports(&info, &ctx.accounts.vault.to_account_info(), collateral)?;
+
+    migration::grow(&info, &ctx.accounts.payer, &ctx.accounts.system_program, Market::SIZE)?;
+
+    let mut market = Market::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(
+        market.creator == ctx.accounts.creator.key(),
+        DegenBetsError::NotMarketCreator
+    );
+
+    market.vault_bump = ctx.bumps.vault;
+    match market.status {
+        MarketStatus::Resolved => market.winning_outcome = market.outcome.map(u8::from),
+        MarketStatus::Voided => market.snapshot_void_prices(),
+        _ => {}
+    }
+
+    // The creator owned the whole pool until their liquidity was claimed
+    let lp_shares = if market.creator_fee_claimed { 0 } else { market.initial_liquidity };
+    market.total_lp_shares = lp_shares;
+    market.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    let creator_liquidity = &mut ctx.accounts.creator_liquidity;
+    creator_liquidity.market = info.key();
+    creator_liquidity.provider = market.creator;
+    creator_liquidity.lp_shares = lp_shares;
+    creator_liquidity.bump = ctx.bumps.creator_liquidity;
+
+    let bond = &mut ctx.accounts.creator_bond;
+    bond.market = info.key();
+    bond.bump = ctx.bumps.creator_bond;
+
+    emit!(MarketMigrated {
+        market: info.key(),
+        payer: ctx.accounts.payer.key(),
+        collateral,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::PositionMigrated;
+use crate::migration;
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy `Position`. It can't deserialize until resized, so
+    /// owner, discriminator and size are checked in the handler
+    #[account(mut, owner = crate::ID @ DegenBetsError::InvalidMigrationAccount)]
+    pub position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a position created before cost basis tracking to the current layout.
+/// The totals and cost basis start at zero, so shares bought before the
+/// migration carry no cost and PnL only reflects what they sell or settle
+/// for. Permissionless; the payer covers the extra rent.
+pub fn handler(ctx: Context<MigratePosition>) -> Result<()> {
+    let position = ctx.accounts.position.to_account_info();
+
+    migration::check_legacy(
+        &position.try_borrow_data()?,
+        Position::DISCRIMINATOR,
+        Position::LEGACY_SIZE,
+        Position::SIZE,
+    )?;
+
+    // Appended bytes are zeroed: no cost, no proceeds, no realized PnL yet
+    migration::grow(
+        &position,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        Position::SIZE,
+    )?;
+
+    emit!(PositionMigrated {
+        position: position.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
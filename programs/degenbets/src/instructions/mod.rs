@@ -1,35 +1,103 @@
 pub mod initialize;
 pub mod create_market;
+pub mod create_categorical_market;
+pub mod create_scalar_market;
 pub mod buy;
 pub mod sell;
+pub mod buy_exact_shares;
+pub mod sell_for_exact_sol;
+pub mod buy_outcome;
+pub mod sell_outcome;
+pub mod split;
+pub mod merge;
+pub mod add_liquidity;
+pub mod remove_liquidity;
+pub mod claim_liquidity;
 pub mod resolve_market;
+pub mod resolve_with_oracle;
 pub mod void_market;
+pub mod cancel_market;
+pub mod vote_resolution;
+pub mod set_resolver_committee;
+pub mod dispute_resolution;
+pub mod settle_dispute;
 pub mod claim_winnings;
 pub mod claim_creator_fee;
 pub mod claim_refund;
+pub mod claim_outcome_winnings;
+pub mod claim_outcome_refund;
 pub mod claim_treasury_fee;
 pub mod toggle_pause;
+pub mod toggle_market_pause;
 pub mod update_fee;
 pub mod update_config;
-pub mod transfer_authority;
+pub mod queue_config_change;
+pub mod execute_config_change;
+pub mod cancel_config_change;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod set_role;
+pub mod set_collateral_mint;
+pub mod remove_collateral_mint;
 pub mod reclaim_stale_market;
 pub mod close_market;
 pub mod close_position;
+pub mod close_outcome_position;
+pub mod close_resolution_votes;
+pub mod migrate_creator_profile;
+pub mod migrate_config;
+pub mod migrate_market;
+pub mod migrate_position;
 
+// Every module exports its own `handler`; callers always go through the
+// module path, the globs are only for the Accounts structs
+#[allow(ambiguous_glob_reexports)]
 pub use initialize::*;
 pub use create_market::*;
+pub use create_categorical_market::*;
 pub use buy::*;
 pub use sell::*;
+pub use buy_outcome::*;
+pub use sell_outcome::*;
+pub use split::*;
+pub use merge::*;
+pub use add_liquidity::*;
+pub use remove_liquidity::*;
+pub use claim_liquidity::*;
 pub use resolve_market::*;
+pub use resolve_with_oracle::*;
 pub use void_market::*;
+pub use cancel_market::*;
+pub use vote_resolution::*;
+pub use set_resolver_committee::*;
+pub use dispute_resolution::*;
+pub use settle_dispute::*;
 pub use claim_winnings::*;
 pub use claim_creator_fee::*;
 pub use claim_refund::*;
+pub use claim_outcome_winnings::*;
+pub use claim_outcome_refund::*;
 pub use claim_treasury_fee::*;
 pub use toggle_pause::*;
+pub use toggle_market_pause::*;
 pub use update_fee::*;
 pub use update_config::*;
-pub use transfer_authority::*;
+pub use queue_config_change::*;
+pub use execute_config_change::*;
+pub use cancel_config_change::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
+pub use set_role::*;
+pub use set_collateral_mint::*;
+pub use remove_collateral_mint::*;
 pub use reclaim_stale_market::*;
 pub use close_market::*;
 pub use close_position::*;
+pub use close_outcome_position::*;
+pub use close_resolution_votes::*;
+pub use migrate_creator_profile::*;
+pub use migrate_config::*;
+pub use migrate_market::*;
+pub use migrate_position::*;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::Config;
+use crate::events::AuthorityTransferProposed;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// First step of an authority transfer. Nothing changes until
+/// `new_authority` signs `accept_authority`; proposing again replaces the
+/// pending key.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    ctx.accounts.config.pending_authority = Some(new_authority);
+
+    emit!(AuthorityTransferProposed {
+        authority: ctx.accounts.config.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
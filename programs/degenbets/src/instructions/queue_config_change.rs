@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ConfigChangeQueued;
+use super::update_config;
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(
+        mut,
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingConfigChange::SIZE,
+        seeds = [b"config_change"],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a config change that anyone can execute once the timelock elapses.
+pub fn handler(ctx: Context<QueueConfigChange>, params: ConfigParams) -> Result<()> {
+    // Reject invalid changes now rather than at execution
+    let mut preview = (*ctx.accounts.config).clone();
+    update_config::apply(&mut preview, &params)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(ctx.accounts.config.config_timelock_seconds)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let pending = &mut ctx.accounts.pending_change;
+    pending.proposer = ctx.accounts.authority.key();
+    pending.params = params.clone();
+    pending.queued_at = now;
+    pending.eta = eta;
+    pending.bump = ctx.bumps.pending_change;
+
+    emit!(ConfigChangeQueued {
+        proposer: pending.proposer,
+        params,
+        eta,
+    });
+
+    Ok(())
+}
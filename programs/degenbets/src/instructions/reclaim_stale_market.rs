@@ -15,6 +15,13 @@ pub struct ReclaimStaleMarket<'info> {
         bump = market.bump,
     )]
     pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", market.creator.as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
 }
 
 pub fn handler(ctx: Context<ReclaimStaleMarket>) -> Result<()> {
@@ -31,10 +38,18 @@ pub fn handler(ctx: Context<ReclaimStaleMarket>) -> Result<()> {
     let market = &mut ctx.accounts.market;
     market.status = MarketStatus::Voided;
     market.outcome = None;
+    market.resolved_at = clock.unix_timestamp;
+    market.void_reason = Some(VoidReason::Stale);
+    market.snapshot_void_prices();
+
+    // Abandoned markets cost the creator more than an authority void
+    ctx.accounts.creator_profile.record_stale_reclaim();
 
     emit!(MarketVoided {
         market: market.key(),
         reason: "Auto-voided: unresolved for 30+ days past resolution time".to_string(),
+        reason_code: VoidReason::Stale,
+        bond_slashed: 0,
     });
 
     Ok(())
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminRole, Config};
+use crate::errors::DegenBetsError;
+use crate::events::CollateralMintUpdated;
+
+#[derive(Accounts)]
+pub struct RemoveCollateralMint<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Remove `mint` from the collateral allowlist. Existing markets in this mint
+/// can still be settled and claimed, but new markets and trades are blocked.
+/// Only allowed while the config timelock is zero.
+pub fn handler(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(config.config_timelock_seconds == 0, DegenBetsError::ConfigTimelocked);
+
+    let len_before = config.collateral_mints.len();
+    config.collateral_mints.retain(|c| c.mint != mint);
+    require!(
+        config.collateral_mints.len() < len_before,
+        DegenBetsError::CollateralNotAllowed
+    );
+
+    emit!(CollateralMintUpdated {
+        mint,
+        allowed: false,
+        min_liquidity: 0,
+        min_trade: 0,
+    });
+
+    Ok(())
+}
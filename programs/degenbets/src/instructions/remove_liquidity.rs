@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::LiquidityRemoved;
+use crate::math;
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"liquidity", market.key().as_ref(), provider.key().as_ref()],
+        bump = liquidity_position.bump,
+        constraint = liquidity_position.market == market.key(),
+        constraint = liquidity_position.provider == provider.key(),
+    )]
+    pub liquidity_position: Account<'info, LiquidityPosition>,
+
+    /// Receives the reserve shares backing the burned LP shares
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = Position::SIZE,
+        seeds = [b"position", market.key().as_ref(), provider.key().as_ref()],
+        bump,
+        constraint = !position.claimed @ DegenBetsError::AlreadyClaimed,
+    )]
+    pub position: Account<'info, Position>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn LP shares of an open market, moving the pro-rata YES and NO reserves
+/// into the provider's position (they can be merged or sold from there).
+pub fn handler(ctx: Context<RemoveLiquidity>, lp_shares: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    require!(ctx.accounts.market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+    require!(
+        ctx.accounts.liquidity_position.lp_shares >= lp_shares,
+        DegenBetsError::InsufficientShares
+    );
+
+    let market = &mut ctx.accounts.market;
+    let (yes_out, no_out) = math::calc_remove_liquidity(
+        lp_shares,
+        market.yes_reserve,
+        market.no_reserve,
+        market.total_lp_shares,
+    )?;
+
+    // The withdrawn shares enter the position at their pool value
+    let (yes_cost, no_cost) = math::calc_shares_value(
+        yes_out,
+        no_out,
+        market.yes_reserve,
+        market.no_reserve,
+    );
+
+    market.yes_reserve = market.yes_reserve
+        .checked_sub(yes_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.no_reserve = market.no_reserve
+        .checked_sub(no_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    market.total_lp_shares = market.total_lp_shares
+        .checked_sub(lp_shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let liquidity_position = &mut ctx.accounts.liquidity_position;
+    liquidity_position.lp_shares = liquidity_position.lp_shares
+        .checked_sub(lp_shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let position = &mut ctx.accounts.position;
+    if position.market == Pubkey::default() {
+        position.market = market.key();
+        position.user = ctx.accounts.provider.key();
+        position.claimed = false;
+        position.bump = ctx.bumps.position;
+    }
+    position.record_cost(yes_cost, no_cost)?;
+    position.yes_shares = position.yes_shares
+        .checked_add(yes_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    position.no_shares = position.no_shares
+        .checked_add(no_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    emit!(LiquidityRemoved {
+        market: market.key(),
+        provider: ctx.accounts.provider.key(),
+        lp_shares_burned: lp_shares,
+        yes_out,
+        no_out,
+    });
+
+    Ok(())
+}
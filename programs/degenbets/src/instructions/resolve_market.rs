@@ -5,8 +5,11 @@ use crate::events::MarketResolved;
 
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
+    /// Resolver role holder, or the market's designated resolver
     #[account(
-        constraint = authority.key() == config.authority,
+        mut,
+        constraint = config.has_role(AdminRole::Resolver, &authority.key())
+            || market.resolver == Some(authority.key()) @ DegenBetsError::UnauthorizedResolver,
     )]
     pub authority: Signer<'info>,
 
@@ -29,19 +32,65 @@ pub struct ResolveMarket<'info> {
         bump = creator_profile.bump,
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
-}
 
-pub fn handler(ctx: Context<ResolveMarket>, outcome: bool) -> Result<()> {
-    let market = &ctx.accounts.market;
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ResolverProfile::SIZE,
+        seeds = [b"resolver", authority.key().as_ref()],
+        bump,
+    )]
+    pub resolver_profile: Account<'info, ResolverProfile>,
+
+    pub system_program: Program<'info, System>,
+}
 
-    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+/// `outcome` is the winning outcome index (binary: 1 = YES, 0 = NO).
+/// Scalar markets ignore it and resolve to `scalar_value` instead.
+pub fn handler(ctx: Context<ResolveMarket>, outcome: u8, scalar_value: Option<i64>) -> Result<()> {
+    require!(ctx.accounts.market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
 
     let clock = Clock::get()?;
     require!(
-        clock.unix_timestamp >= market.resolution_timestamp,
+        clock.unix_timestamp >= ctx.accounts.market.resolution_timestamp,
         DegenBetsError::MarketNotReady
     );
 
+    let total_pot = ctx.accounts.market.total_minted;
+    settle(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.creator_profile,
+        outcome,
+        scalar_value,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    // Update resolver profile
+    let profile = &mut ctx.accounts.resolver_profile;
+    if profile.wallet == Pubkey::default() {
+        profile.wallet = ctx.accounts.authority.key();
+        profile.reputation_score = 100;
+        profile.bump = ctx.bumps.resolver_profile;
+    }
+    profile.markets_resolved += 1;
+    profile.total_volume_resolved = profile.total_volume_resolved
+        .checked_add(total_pot)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Record the resolution, snapshot fees and credit the creator. Shared by the
+/// authority and oracle resolution paths.
+pub(crate) fn settle(
+    market: &mut Account<Market>,
+    profile: &mut Account<CreatorProfile>,
+    outcome: u8,
+    scalar_value: Option<i64>,
+    resolved_by: Pubkey,
+    now: i64,
+) -> Result<()> {
     // In AMM model, total pot = total_minted (vault balance)
     let total_pot = market.total_minted;
 
@@ -56,20 +105,19 @@ pub fn handler(ctx: Context<ResolveMarket>, outcome: bool) -> Result<()> {
         .ok_or(DegenBetsError::MathOverflow)?
         / 10000u128) as u64;
 
-    // NOTE: Treasury fee is NOT transferred here. It stays in the market PDA
+    // NOTE: Treasury fee is NOT transferred here. It stays in the market vault
     // during the challenge period and is collected later via claim_treasury_fee.
     // This ensures full refunds are possible if the market is voided during challenge.
 
     // Update market
-    let market = &mut ctx.accounts.market;
     market.status = MarketStatus::Resolved;
-    market.outcome = Some(outcome);
-    market.resolved_at = clock.unix_timestamp;
+    market.set_resolution(outcome, scalar_value)?;
+    market.resolved_at = now;
+    market.resolved_by = resolved_by;
     market.treasury_fee = treasury_fee;
     market.creator_fee = creator_fee;
 
     // Update creator profile
-    let profile = &mut ctx.accounts.creator_profile;
     profile.markets_resolved += 1;
     profile.total_volume_generated = profile.total_volume_generated
         .checked_add(total_pot)
@@ -77,7 +125,9 @@ pub fn handler(ctx: Context<ResolveMarket>, outcome: bool) -> Result<()> {
 
     emit!(MarketResolved {
         market: market.key(),
-        outcome,
+        outcome: market.outcome.unwrap_or_default(),
+        winning_outcome: market.winning_outcome.unwrap_or_default(),
+        resolved_value: market.resolved_value,
         total_pot,
         treasury_fee,
         creator_fee,
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::OracleResolved;
+use crate::oracle;
+use super::resolve_market;
+
+#[derive(Accounts)]
+pub struct ResolveWithOracle<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", market.creator.as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    /// CHECK: Price account, validated against the market's oracle condition
+    /// and parsed in the handler
+    pub price_feed: UncheckedAccount<'info>,
+}
+
+/// Permissionless resolution from the market's oracle feed. The feed's price
+/// must be its first update at or after `resolution_timestamp`, published
+/// within `oracle::MAX_PUBLISH_DELAY_SECONDS` of it; once the feed has moved
+/// on the market is left to its resolver (`resolve_market` or the
+/// committee). The result is still subject to the challenge period like any
+/// other resolution.
+pub fn handler(ctx: Context<ResolveWithOracle>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let config = &ctx.accounts.config;
+
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= market.resolution_timestamp,
+        DegenBetsError::MarketNotReady
+    );
+
+    let condition = market.oracle.clone().ok_or(DegenBetsError::OracleNotConfigured)?;
+    require!(config.oracle_program != Pubkey::default(), DegenBetsError::OracleNotConfigured);
+
+    // Feed must be the one fixed at creation and owned by the oracle program
+    let feed = &ctx.accounts.price_feed;
+    require!(
+        feed.key() == condition.feed && *feed.owner == config.oracle_program,
+        DegenBetsError::InvalidOracleAccount
+    );
+
+    let price = oracle::parse_price_account(&feed.try_borrow_data()?)?;
+    require!(price.expo == condition.expo, DegenBetsError::InvalidOracleAccount);
+    oracle::check_price(&price, market.resolution_timestamp, clock.unix_timestamp)?;
+
+    let (outcome, scalar_value) = match market.market_type {
+        MarketType::Scalar => (0, Some(price.price)),
+        _ => (condition.outcome_for(price.price), None),
+    };
+
+    resolve_market::settle(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.creator_profile,
+        outcome,
+        scalar_value,
+        Pubkey::default(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(OracleResolved {
+        market: ctx.accounts.market.key(),
+        feed: condition.feed,
+        price: price.price,
+        conf: price.conf,
+        expo: price.expo,
+        publish_time: price.timestamp,
+        caller: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::SharesSold;
 use crate::math;
+use crate::collateral::{self, TokenCollateral};
 
 #[derive(Accounts)]
 pub struct Sell<'info> {
@@ -19,9 +21,18 @@ pub struct Sell<'info> {
         mut,
         seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
     )]
     pub market: Account<'info, Market>,
 
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
@@ -30,14 +41,41 @@ pub struct Sell<'info> {
         constraint = position.user == user.key(),
     )]
     pub position: Account<'info, Position>,
+
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
-pub fn handler(ctx: Context<Sell>, shares: u64, side: bool) -> Result<()> {
+pub fn handler(
+    ctx: Context<Sell>,
+    shares: u64,
+    side: bool,
+    min_sol_out: u64,
+    min_price_bps: Option<u16>,
+) -> Result<()> {
     let config = &ctx.accounts.config;
 
-    require!(!config.paused, DegenBetsError::PlatformPaused);
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
     require!(shares > 0, DegenBetsError::ZeroBetAmount);
 
+    // Delisted collateral mints can no longer trade
+    config.min_trade_for(&ctx.accounts.market.collateral_mint)?;
+
     // Validate market state (read fields without holding borrow)
     let market_key = ctx.accounts.market.key();
     let market_status = ctx.accounts.market.status.clone();
@@ -52,26 +90,33 @@ pub fn handler(ctx: Context<Sell>, shares: u64, side: bool) -> Result<()> {
     );
 
     // Validate sufficient shares
-    if side {
-        require!(ctx.accounts.position.yes_shares >= shares, DegenBetsError::InsufficientShares);
-    } else {
-        require!(ctx.accounts.position.no_shares >= shares, DegenBetsError::InsufficientShares);
-    }
+    require!(ctx.accounts.position.shares(side) >= shares, DegenBetsError::InsufficientShares);
 
-    // Sell shares through the AMM (users can only hold one side)
+    // Sell shares through the AMM (only the given side; the other side is
+    // untouched), at the trader's tier fee
+    let fee_bps = config.trade_fee_bps(&ctx.accounts.market, Some(&ctx.accounts.trader_profile));
     let position = &mut ctx.accounts.position;
     let market = &mut ctx.accounts.market;
 
     let (total_sol_out, new_ry, new_rn) = if side {
-        math::calc_sell_yes(shares, market.yes_reserve, market.no_reserve, market.swap_fee_bps)?
+        math::calc_sell_yes(shares, market.yes_reserve, market.no_reserve, fee_bps)?
     } else {
-        math::calc_sell_no(shares, market.yes_reserve, market.no_reserve, market.swap_fee_bps)?
+        math::calc_sell_no(shares, market.yes_reserve, market.no_reserve, fee_bps)?
     };
 
+    // Slippage: bound the fill against trades landing ahead of this one
+    require!(total_sol_out >= min_sol_out, DegenBetsError::SlippageExceeded);
+    if let Some(min_bps) = min_price_bps {
+        require!(
+            math::side_price_bps(new_ry, new_rn, side) >= min_bps as u64,
+            DegenBetsError::SlippageExceeded
+        );
+    }
+
     market.yes_reserve = new_ry;
     market.no_reserve = new_rn;
 
-    // Update total_minted (decreased by SOL leaving the vault)
+    // Update total_minted (decreased by SOL leaving the vault; fee sets stay in the pool)
     market.total_minted = market.total_minted
         .checked_sub(total_sol_out)
         .ok_or(DegenBetsError::MathOverflow)?;
@@ -79,42 +124,44 @@ pub fn handler(ctx: Context<Sell>, shares: u64, side: bool) -> Result<()> {
     let final_ry = market.yes_reserve;
     let final_rn = market.no_reserve;
 
-    // Rent guard
-    let rent = Rent::get()?;
-    let min_balance = rent.minimum_balance(Market::SIZE);
-    let market_lamports = market.to_account_info().lamports();
-    require!(
-        market_lamports.checked_sub(total_sol_out).unwrap_or(0) >= min_balance,
-        DegenBetsError::InsufficientRentBalance
-    );
-
-    // Transfer SOL from market PDA to user (checked arithmetic)
-    {
-        let market_info = market.to_account_info();
-        let mut market_lamps = market_info.try_borrow_mut_lamports()?;
-        let user_info = ctx.accounts.user.to_account_info();
-        let mut user_lamps = user_info.try_borrow_mut_lamports()?;
-        **market_lamps = market_lamps
-            .checked_sub(total_sol_out)
-            .ok_or(DegenBetsError::MathOverflow)?;
-        **user_lamps = user_lamps
-            .checked_add(total_sol_out)
-            .ok_or(DegenBetsError::MathOverflow)?;
-    }
+    // Transfer collateral from vault to user
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::withdraw(
+        market,
+        &ctx.accounts.vault,
+        &ctx.accounts.user.to_account_info(),
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        total_sol_out,
+    )?;
 
     // Update position (deduct sold shares)
-    if side {
-        position.yes_shares = position.yes_shares
-            .checked_sub(shares)
-            .ok_or(DegenBetsError::MathOverflow)?;
-    } else {
-        position.no_shares = position.no_shares
-            .checked_sub(shares)
-            .ok_or(DegenBetsError::MathOverflow)?;
-    }
+    let pnl_before = position.realized_pnl;
+    let (yes_sold, no_sold) = if side { (shares, 0) } else { (0, shares) };
+    position.record_proceeds(total_sol_out, yes_sold, no_sold)?;
+    let held = position.shares_mut(side);
+    *held = held
+        .checked_sub(shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
 
     let price_after = math::price_yes_bps(final_ry, final_rn);
 
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_trade(total_sol_out, false)?;
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(&ctx.accounts.market, &ctx.accounts.vault)?;
+
     emit!(SharesSold {
         market: market_key,
         user: ctx.accounts.user.key(),
@@ -122,6 +169,12 @@ pub fn handler(ctx: Context<Sell>, shares: u64, side: bool) -> Result<()> {
         shares_sold: shares,
         sol_received: total_sol_out,
         price_after,
+        min_sol_out,
+        min_price_bps,
+        fee_bps,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
     });
 
     Ok(())
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::errors::DegenBetsError;
+use crate::math;
+use super::sell::{self, Sell};
+
+/// Exact-output sell: solve for the shares needed to receive `sol_out`, then run
+/// the regular sell with that share count and `sol_out` as the slippage floor.
+pub fn handler(ctx: Context<Sell>, sol_out: u64, side: bool, max_shares_in: u64) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let fee_bps = ctx.accounts.config.trade_fee_bps(market, Some(&ctx.accounts.trader_profile));
+
+    let (shares_in, _, _) = if side {
+        math::calc_sell_yes_exact_sol(sol_out, market.yes_reserve, market.no_reserve, fee_bps)?
+    } else {
+        math::calc_sell_no_exact_sol(sol_out, market.yes_reserve, market.no_reserve, fee_bps)?
+    };
+    require!(shares_in <= max_shares_in, DegenBetsError::SlippageExceeded);
+
+    sell::handler(ctx, shares_in, side, sol_out, None)
+}
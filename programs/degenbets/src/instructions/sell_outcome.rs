@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::OutcomeSharesSold;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct SellOutcome<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type == MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.market == market.key(),
+        constraint = position.user == user.key(),
+    )]
+    pub position: Account<'info, OutcomePosition>,
+
+    /// Trading record, created on first use so every sale and settlement is recorded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = TraderProfile::SIZE,
+        seeds = [b"trader", user.key().as_ref()],
+        bump,
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Sell one outcome of a categorical market back to the N-outcome pool.
+pub fn handler(
+    ctx: Context<SellOutcome>,
+    shares: u64,
+    outcome_index: u8,
+    min_sol_out: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let market = &ctx.accounts.market;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    require!(shares > 0, DegenBetsError::ZeroBetAmount);
+
+    // Delisted collateral mints can no longer trade
+    config.min_trade_for(&market.collateral_mint)?;
+
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+    require!(
+        (outcome_index as usize) < market.outcome_reserves.len(),
+        DegenBetsError::InvalidOutcomeIndex
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < market.resolution_timestamp - config.betting_cutoff_seconds,
+        DegenBetsError::BettingClosed
+    );
+
+    // Validate sufficient shares
+    require!(
+        ctx.accounts.position.shares[outcome_index as usize] >= shares,
+        DegenBetsError::InsufficientShares
+    );
+
+    // Sell shares through the AMM
+    let fee_bps = ctx.accounts.config
+        .trade_fee_bps(&ctx.accounts.market, Some(&ctx.accounts.trader_profile));
+    let market = &mut ctx.accounts.market;
+    let (sol_out, new_reserves) = math::calc_sell_outcome(
+        shares,
+        &market.outcome_reserves,
+        outcome_index as usize,
+        fee_bps,
+    )?;
+
+    // Slippage: bound the fill against trades landing ahead of this one
+    require!(sol_out >= min_sol_out, DegenBetsError::SlippageExceeded);
+
+    market.outcome_reserves = new_reserves;
+
+    // Update total_minted (decreased by collateral leaving the vault; fee sets stay in the pool)
+    market.total_minted = market.total_minted
+        .checked_sub(sol_out)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Transfer collateral from vault to user
+    let token = TokenCollateral::resolve(
+        market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::withdraw(
+        market,
+        &ctx.accounts.vault,
+        &ctx.accounts.user.to_account_info(),
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        sol_out,
+    )?;
+
+    // Update position (deduct sold shares)
+    let position = &mut ctx.accounts.position;
+    let pnl_before = position.realized_pnl;
+    position.record_sale(outcome_index as usize, sol_out, shares)?;
+    let held = &mut position.shares[outcome_index as usize];
+    *held = held
+        .checked_sub(shares)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let trader = &mut ctx.accounts.trader_profile;
+    trader.init_if_new(ctx.accounts.user.key(), ctx.bumps.trader_profile);
+    trader.record_trade(sol_out, false)?;
+    trader.record_pnl(
+        position.realized_pnl
+            .checked_sub(pnl_before)
+            .ok_or(DegenBetsError::MathOverflow)?,
+    )?;
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(&ctx.accounts.market, &ctx.accounts.vault)?;
+
+    emit!(OutcomeSharesSold {
+        market: ctx.accounts.market.key(),
+        user: ctx.accounts.user.key(),
+        outcome_index,
+        shares_sold: shares,
+        sol_received: sol_out,
+        prices_bps: math::outcome_prices_bps(&ctx.accounts.market.outcome_reserves),
+        fee_bps,
+        total_cost_lamports: ctx.accounts.position.total_cost_lamports,
+        total_proceeds_lamports: ctx.accounts.position.total_proceeds_lamports,
+        realized_pnl: ctx.accounts.position.realized_pnl,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminRole, CollateralMint, Config};
+use crate::errors::DegenBetsError;
+use crate::events::CollateralMintUpdated;
+
+#[derive(Accounts)]
+pub struct SetCollateralMint<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Allow `mint` as market collateral, or update its minimums if already allowed.
+/// Only allowed while the config timelock is zero.
+pub fn handler(
+    ctx: Context<SetCollateralMint>,
+    mint: Pubkey,
+    min_liquidity: u64,
+    min_trade: u64,
+) -> Result<()> {
+    require!(mint != Pubkey::default(), DegenBetsError::InvalidConfigParam);
+    require!(min_trade > 0, DegenBetsError::InvalidConfigParam);
+
+    let config = &mut ctx.accounts.config;
+    require!(config.config_timelock_seconds == 0, DegenBetsError::ConfigTimelocked);
+
+    let entry = CollateralMint { mint, min_liquidity, min_trade };
+
+    if let Some(existing) = config.collateral_mints.iter_mut().find(|c| c.mint == mint) {
+        *existing = entry;
+    } else {
+        require!(
+            config.collateral_mints.len() < Config::MAX_COLLATERAL_MINTS,
+            DegenBetsError::TooManyCollateralMints
+        );
+        config.collateral_mints.push(entry);
+    }
+
+    emit!(CollateralMintUpdated {
+        mint,
+        allowed: true,
+        min_liquidity,
+        min_trade,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ResolverCommitteeUpdated;
+
+#[derive(Accounts)]
+pub struct SetResolverCommittee<'info> {
+    #[account(
+        mut,
+        constraint = config.has_role(AdminRole::Resolver, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ResolverCommittee::SIZE,
+        seeds = [b"committee"],
+        bump,
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or replace the resolver committee. Votes already cast by members
+/// who are removed stop counting.
+pub fn handler(ctx: Context<SetResolverCommittee>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= ResolverCommittee::MAX_MEMBERS,
+        DegenBetsError::InvalidCommittee
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        DegenBetsError::InvalidCommittee
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(!members[..i].contains(member), DegenBetsError::InvalidCommittee);
+    }
+
+    let committee = &mut ctx.accounts.committee;
+    committee.members = members.clone();
+    committee.threshold = threshold;
+    committee.bump = ctx.bumps.committee;
+
+    emit!(ResolverCommitteeUpdated {
+        members,
+        threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminRole, Config};
+use crate::events::RoleUpdated;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Assign `role` to `holder`, replacing the previous holder.
+/// `Pubkey::default()` revokes the role.
+pub fn handler(ctx: Context<SetRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let slot = match role {
+        AdminRole::Pauser => &mut config.pauser,
+        AdminRole::Resolver => &mut config.resolver,
+        AdminRole::ConfigAdmin => &mut config.config_admin,
+        AdminRole::TreasuryClaimer => &mut config.treasury_claimer,
+    };
+    let old_holder = std::mem::replace(slot, holder);
+
+    emit!(RoleUpdated {
+        role,
+        old_holder,
+        new_holder: holder,
+    });
+
+    Ok(())
+}
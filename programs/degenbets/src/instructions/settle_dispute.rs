@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::DisputeSettled;
+use crate::collateral;
+use super::void_market;
+
+#[derive(Accounts)]
+pub struct SettleDispute<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::Resolver, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", market.key().as_ref()],
+        bump = dispute.bump,
+        has_one = market,
+        has_one = disputer,
+        close = disputer,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Disputer receives the dispute account's rent, plus the bond if upheld
+    #[account(mut)]
+    pub disputer: SystemAccount<'info>,
+
+    /// Treasury wallet receiving slashed bonds
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Creator bond escrow, paid to the disputer when the resolution is overturned
+    #[account(
+        mut,
+        seeds = [b"bond", market.key().as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    /// Creator's profile, charged with the void when the market is voided
+    #[account(
+        mut,
+        seeds = [b"creator", market.creator.as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Profile of whoever resolved the market, when it was resolved via
+    /// `resolve_market`
+    #[account(
+        mut,
+        seeds = [b"resolver", market.resolved_by.as_ref()],
+        bump = resolver_profile.bump,
+    )]
+    pub resolver_profile: Option<Account<'info, ResolverProfile>>,
+}
+
+/// Settle a disputed resolution. With a correction (`corrected_outcome`, or
+/// `corrected_value` for scalar markets) the resolution is overturned; with a
+/// `void_reason` the market is voided instead, like `void_market`. Either way
+/// the dispute bond is returned and the creator bond is forfeited to the
+/// disputer as a reward. Otherwise the resolution stands and the dispute bond
+/// is slashed to the treasury.
+///
+/// The reward never comes out of the vault: the fees booked at resolution are
+/// already excluded from the prize pool every winning share is paid from.
+pub fn handler(
+    ctx: Context<SettleDispute>,
+    corrected_outcome: Option<u8>,
+    corrected_value: Option<i64>,
+    void_reason: Option<VoidReason>,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    require!(market.status == MarketStatus::Disputed, DegenBetsError::MarketNotDisputed);
+
+    let bond = ctx.accounts.dispute.bond;
+    let corrected = corrected_outcome.is_some() || corrected_value.is_some();
+    require!(
+        !(corrected && void_reason.is_some()),
+        DegenBetsError::ConflictingDisputeSettlement
+    );
+    if corrected {
+        // A correction must actually change the result
+        require!(
+            corrected_outcome.is_none_or(|o| Some(o) != market.winning_outcome)
+                && corrected_value.is_none_or(|v| Some(v) != market.resolved_value),
+            DegenBetsError::InvalidOutcomeIndex
+        );
+    }
+    let overturned = corrected || void_reason.is_some();
+
+    let reward = if overturned {
+        // Dispute bond goes back with the dispute account's rent on close;
+        // the creator bond (whatever is left of it) is the reward
+        let reward = market.creator_bond;
+        collateral::move_lamports(
+            &ctx.accounts.creator_bond.to_account_info(),
+            &ctx.accounts.disputer.to_account_info(),
+            reward,
+        )?;
+        reward
+    } else {
+        // Slash the bond to treasury
+        collateral::move_lamports(
+            &ctx.accounts.dispute.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            bond,
+        )?;
+        0
+    };
+
+    let market = &mut ctx.accounts.market;
+    market.status = MarketStatus::Resolved;
+    if overturned {
+        market.creator_bond = 0;
+        market.resolution_overturned = true;
+
+        if market.resolved_by != Pubkey::default() {
+            ctx.accounts.resolver_profile
+                .as_mut()
+                .ok_or(DegenBetsError::MissingResolverProfile)?
+                .record_overturn();
+        }
+        // The correction is the authority's, not the original resolver's
+        market.resolved_by = Pubkey::default();
+    }
+
+    let voided = void_reason.is_some();
+    if let Some(reason_code) = void_reason {
+        // The bond already went to the disputer, so nothing is left to slash
+        void_market::void(
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.creator_profile,
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.creator_bond.to_account_info(),
+            String::from("Voided on dispute"),
+            reason_code,
+        )?;
+    } else if corrected {
+        ctx.accounts.market.set_resolution(corrected_outcome.unwrap_or_default(), corrected_value)?;
+    }
+
+    let market = &ctx.accounts.market;
+    emit!(DisputeSettled {
+        market: market.key(),
+        disputer: ctx.accounts.disputer.key(),
+        overturned,
+        winning_outcome: market.winning_outcome.unwrap_or_default(),
+        resolved_value: market.resolved_value,
+        bond,
+        reward,
+        voided,
+    });
+
+    Ok(())
+}
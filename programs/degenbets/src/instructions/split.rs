@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::SetsSplit;
+use crate::math;
+use crate::collateral::{self, TokenCollateral};
+
+#[derive(Accounts)]
+pub struct Split<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.market_type != MarketType::Categorical @ DegenBetsError::InvalidMarketType,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Collateral vault PDA (lamports for SOL markets, token account for token markets)
+    #[account(
+        mut,
+        seeds = [b"vault", market.key().as_ref()],
+        bump = market.vault_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Position::SIZE,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = !position.claimed @ DegenBetsError::AlreadyClaimed,
+    )]
+    pub position: Account<'info, Position>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Mint `amount` complete sets (equal YES and NO shares) for collateral at 1:1,
+/// without swapping through the AMM.
+pub fn handler(ctx: Context<Split>, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused(Config::PAUSE_TRADING), DegenBetsError::PlatformPaused);
+    require!(!ctx.accounts.market.paused, DegenBetsError::MarketPaused);
+    let market = &ctx.accounts.market;
+    require!(
+        amount >= config.min_trade_for(&market.collateral_mint)?,
+        DegenBetsError::BelowMinBet
+    );
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < market.resolution_timestamp - config.betting_cutoff_seconds,
+        DegenBetsError::BettingClosed
+    );
+
+    // Transfer collateral from user to market
+    let token = TokenCollateral::resolve(
+        &ctx.accounts.market,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    collateral::deposit(
+        &ctx.accounts.vault,
+        &ctx.accounts.user,
+        token.as_ref(),
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    let market = &mut ctx.accounts.market;
+    market.total_minted = market.total_minted
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Update position
+    let position = &mut ctx.accounts.position;
+    if position.market == Pubkey::default() {
+        position.market = market.key();
+        position.user = ctx.accounts.user.key();
+        position.claimed = false;
+        position.bump = ctx.bumps.position;
+    }
+
+    // Each side's cost basis is its share of the set at the pool price
+    let yes_cost = ((amount as u128)
+        * math::price_yes_bps(market.yes_reserve, market.no_reserve) as u128
+        / 10000) as u64;
+    position.record_cost(yes_cost, amount - yes_cost)?;
+    position.yes_shares = position.yes_shares
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    position.no_shares = position.no_shares
+        .checked_add(amount)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    // Reconcile: the vault must still back every outstanding set
+    collateral::assert_backed(market, &ctx.accounts.vault)?;
+
+    emit!(SetsSplit {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+        total_cost_lamports: position.total_cost_lamports,
+        total_proceeds_lamports: position.total_proceeds_lamports,
+        realized_pnl: position.realized_pnl,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::MarketPauseToggled;
+
+#[derive(Accounts)]
+pub struct ToggleMarketPause<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::Pauser, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Freeze or unfreeze trading and claims on a single market.
+pub fn handler(ctx: Context<ToggleMarketPause>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.paused = !market.paused;
+
+    emit!(MarketPauseToggled {
+        market: market.key(),
+        paused: market.paused,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::state::{AdminRole, Config};
+use crate::errors::DegenBetsError;
 use crate::events::PlatformPauseToggled;
 
 #[derive(Accounts)]
 pub struct TogglePause<'info> {
     #[account(
-        constraint = authority.key() == config.authority,
+        constraint = config.has_role(AdminRole::Pauser, &authority.key()) @ DegenBetsError::MissingRole,
     )]
     pub authority: Signer<'info>,
 
@@ -17,12 +18,18 @@ pub struct TogglePause<'info> {
     pub config: Account<'info, Config>,
 }
 
-pub fn handler(ctx: Context<TogglePause>) -> Result<()> {
+/// Flip the given `Config::PAUSE_*` flags.
+pub fn handler(ctx: Context<TogglePause>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !Config::PAUSE_ALL == 0,
+        DegenBetsError::InvalidConfigParam
+    );
+
     let config = &mut ctx.accounts.config;
-    config.paused = !config.paused;
+    config.pause_flags ^= flags;
 
     emit!(PlatformPauseToggled {
-        paused: config.paused,
+        pause_flags: config.pause_flags,
     });
 
     Ok(())
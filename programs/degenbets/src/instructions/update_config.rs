@@ -1,69 +1,105 @@
 use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::state::{AdminRole, Config, ConfigParams};
 use crate::errors::DegenBetsError;
+use crate::events::ConfigUpdated;
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"config"],
         bump = config.bump,
-        has_one = authority,
     )]
     pub config: Account<'info, Config>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateConfig>,
-    treasury: Option<Pubkey>,
-    min_liquidity_lamports: Option<u64>,
-    treasury_rake_bps: Option<u16>,
-    creator_rake_bps: Option<u16>,
-    min_trade_lamports: Option<u64>,
-    betting_cutoff_seconds: Option<i64>,
-    challenge_period_seconds: Option<i64>,
-    swap_fee_bps: Option<u16>,
-) -> Result<()> {
+/// Apply a config change immediately. Only allowed while the config
+/// timelock is zero; otherwise changes go through `queue_config_change`.
+pub fn handler(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    require!(config.config_timelock_seconds == 0, DegenBetsError::ConfigTimelocked);
+
+    let old = params.current_values(config);
+    apply(config, &params)?;
 
-    if let Some(t) = treasury {
+    emit!(ConfigUpdated {
+        old,
+        new: params,
+    });
+
+    Ok(())
+}
+
+/// Validate and apply `params` to `config`. Shared by `update_config` and
+/// `execute_config_change`.
+pub(crate) fn apply(config: &mut Config, params: &ConfigParams) -> Result<()> {
+    if let Some(t) = params.treasury {
         config.treasury = t;
     }
-    if let Some(f) = min_liquidity_lamports {
+    if let Some(f) = params.min_liquidity_lamports {
         config.min_liquidity_lamports = f;
     }
-    if let Some(bps) = treasury_rake_bps {
+    if let Some(bps) = params.treasury_rake_bps {
         require!(bps <= 10000, DegenBetsError::InvalidRakeBps);
         config.treasury_rake_bps = bps;
     }
-    if let Some(bps) = creator_rake_bps {
+    if let Some(bps) = params.creator_rake_bps {
         require!(bps <= 10000, DegenBetsError::InvalidRakeBps);
         config.creator_rake_bps = bps;
     }
-    if treasury_rake_bps.is_some() || creator_rake_bps.is_some() {
+    if params.treasury_rake_bps.is_some() || params.creator_rake_bps.is_some() {
         require!(
             (config.treasury_rake_bps as u32 + config.creator_rake_bps as u32) <= 10000,
             DegenBetsError::InvalidRakeBps
         );
     }
-    if let Some(m) = min_trade_lamports {
+    if let Some(m) = params.min_trade_lamports {
         require!(m > 0, DegenBetsError::InvalidConfigParam);
         config.min_trade_lamports = m;
     }
-    if let Some(b) = betting_cutoff_seconds {
+    if let Some(b) = params.betting_cutoff_seconds {
         require!(b > 0, DegenBetsError::InvalidConfigParam);
         config.betting_cutoff_seconds = b;
     }
-    if let Some(c) = challenge_period_seconds {
+    if let Some(c) = params.challenge_period_seconds {
         require!(c > 0, DegenBetsError::InvalidConfigParam);
         config.challenge_period_seconds = c;
     }
-    if let Some(bps) = swap_fee_bps {
+    if let Some(bps) = params.swap_fee_bps {
         require!(bps <= 10000, DegenBetsError::InvalidRakeBps);
         config.swap_fee_bps = bps;
     }
+    if let Some(b) = params.dispute_bond_lamports {
+        require!(b > 0, DegenBetsError::InvalidConfigParam);
+        config.dispute_bond_lamports = b;
+    }
+    if let Some(p) = params.oracle_program {
+        config.oracle_program = p;
+    }
+    if let Some(t) = params.config_timelock_seconds {
+        require!(
+            (0..=Config::MAX_CONFIG_TIMELOCK_SECONDS).contains(&t),
+            DegenBetsError::InvalidConfigParam
+        );
+        config.config_timelock_seconds = t;
+    }
+    if let Some(b) = params.creator_bond_lamports {
+        config.creator_bond_lamports = b;
+    }
+    if let Some(tiers) = &params.fee_tiers {
+        require!(tiers.len() <= Config::MAX_FEE_TIERS, DegenBetsError::InvalidConfigParam);
+        require!(
+            tiers.iter().all(|t| t.discount_bps <= 10000)
+                && tiers.windows(2).all(|w| w[0].min_volume < w[1].min_volume),
+            DegenBetsError::InvalidConfigParam
+        );
+        config.fee_tiers = tiers.clone();
+    }
 
     Ok(())
 }
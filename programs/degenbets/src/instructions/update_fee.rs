@@ -1,20 +1,41 @@
 use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::state::{AdminRole, Config, ConfigParams};
+use crate::errors::DegenBetsError;
+use crate::events::ConfigUpdated;
 
 #[derive(Accounts)]
 pub struct UpdateFee<'info> {
+    #[account(
+        constraint = config.has_role(AdminRole::ConfigAdmin, &authority.key()) @ DegenBetsError::MissingRole,
+    )]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"config"],
         bump = config.bump,
-        has_one = authority,
     )]
     pub config: Account<'info, Config>,
 }
 
+/// Set the SOL minimum liquidity immediately. Like `update_config`, only
+/// allowed while the config timelock is zero.
 pub fn handler(ctx: Context<UpdateFee>, new_fee_lamports: u64) -> Result<()> {
-    ctx.accounts.config.min_liquidity_lamports = new_fee_lamports;
+    let config = &mut ctx.accounts.config;
+    require!(config.config_timelock_seconds == 0, DegenBetsError::ConfigTimelocked);
+    let old_fee_lamports = config.min_liquidity_lamports;
+    config.min_liquidity_lamports = new_fee_lamports;
+
+    emit!(ConfigUpdated {
+        old: ConfigParams {
+            min_liquidity_lamports: Some(old_fee_lamports),
+            ..Default::default()
+        },
+        new: ConfigParams {
+            min_liquidity_lamports: Some(new_fee_lamports),
+            ..Default::default()
+        },
+    });
+
     Ok(())
 }
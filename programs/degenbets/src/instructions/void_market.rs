@@ -2,11 +2,12 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::DegenBetsError;
 use crate::events::MarketVoided;
+use crate::collateral;
 
 #[derive(Accounts)]
 pub struct VoidMarket<'info> {
     #[account(
-        constraint = authority.key() == config.authority,
+        constraint = config.has_role(AdminRole::Resolver, &authority.key()) @ DegenBetsError::MissingRole,
     )]
     pub authority: Signer<'info>,
 
@@ -29,10 +30,32 @@ pub struct VoidMarket<'info> {
         bump = creator_profile.bump,
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
+
+    /// Treasury wallet receiving slashed creator bonds
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", market.key().as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    /// Profile of whoever resolved the market, when voiding a resolution
+    #[account(
+        mut,
+        seeds = [b"resolver", market.resolved_by.as_ref()],
+        bump = resolver_profile.bump,
+    )]
+    pub resolver_profile: Option<Account<'info, ResolverProfile>>,
 }
 
-pub fn handler(ctx: Context<VoidMarket>, reason: String) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+pub fn handler(ctx: Context<VoidMarket>, reason: String, reason_code: VoidReason) -> Result<()> {
+    let market = &ctx.accounts.market;
     let config = &ctx.accounts.config;
 
     // Allow voiding Open markets, or Resolved markets still within challenge period
@@ -42,18 +65,56 @@ pub fn handler(ctx: Context<VoidMarket>, reason: String) -> Result<()> {
             && clock.unix_timestamp < market.resolved_at + config.challenge_period_seconds);
     require!(can_void, DegenBetsError::MarketNotVoidable);
 
+    // Voiding a resolution counts against the resolver
+    if market.status == MarketStatus::Resolved && market.resolved_by != Pubkey::default() {
+        ctx.accounts.resolver_profile
+            .as_mut()
+            .ok_or(DegenBetsError::MissingResolverProfile)?
+            .record_overturn();
+    }
+
+    void(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.creator_profile,
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.creator_bond.to_account_info(),
+        reason,
+        reason_code,
+    )
+}
+
+/// Void the market. On creator fault the creator bond is slashed from its
+/// escrow account to the treasury and the creator loses reputation; otherwise the bond is returned
+/// with `claim_creator_fee`. Shared by the authority and committee paths.
+pub(crate) fn void<'info>(
+    market: &mut Account<'info, Market>,
+    profile: &mut Account<'info, CreatorProfile>,
+    treasury: &AccountInfo<'info>,
+    creator_bond: &AccountInfo<'info>,
+    reason: String,
+    reason_code: VoidReason,
+) -> Result<()> {
     market.status = MarketStatus::Voided;
     market.outcome = None;
+    market.resolved_at = Clock::get()?.unix_timestamp;
+    market.void_reason = Some(reason_code.clone());
+    market.snapshot_void_prices();
+
+    let creator_fault = reason_code.is_creator_fault();
+    let bond_slashed = if creator_fault { market.creator_bond } else { 0 };
+    if bond_slashed > 0 {
+        collateral::move_lamports(creator_bond, treasury, bond_slashed)?;
+        market.creator_bond = 0;
+    }
 
-    // Update creator profile - reputation hit
-    let profile = &mut ctx.accounts.creator_profile;
-    profile.markets_voided += 1;
-    // Lose 10 reputation points per void, minimum 0
-    profile.reputation_score = profile.reputation_score.saturating_sub(10);
+    // Update creator profile - reputation hit on creator fault only
+    profile.record_void(creator_fault);
 
     emit!(MarketVoided {
         market: market.key(),
         reason,
+        reason_code,
+        bond_slashed,
     });
 
     Ok(())
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DegenBetsError;
+use crate::events::ResolutionVoteCast;
+use super::{resolve_market, void_market};
+
+#[derive(Accounts)]
+pub struct VoteResolution<'info> {
+    #[account(
+        mut,
+        constraint = committee.is_member(&member.key()) @ DegenBetsError::NotCommitteeMember,
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"committee"],
+        bump = committee.bump,
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = ResolutionVotes::SIZE,
+        seeds = [b"votes", market.key().as_ref()],
+        bump,
+    )]
+    pub votes: Account<'info, ResolutionVotes>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", market.creator.as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Treasury wallet receiving slashed creator bonds
+    #[account(
+        mut,
+        constraint = treasury.key() == config.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", market.key().as_ref()],
+        bump = creator_bond.bump,
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cast (or change) a committee member's vote on an open market. The market
+/// resolves or voids as soon as `threshold` current members agree.
+pub fn handler(ctx: Context<VoteResolution>, vote: CommitteeVote) -> Result<()> {
+    let market = &ctx.accounts.market;
+    require!(market.status == MarketStatus::Open, DegenBetsError::MarketNotOpen);
+    let vote = vote.normalized(&market.market_type);
+
+    let clock = Clock::get()?;
+    if let CommitteeVote::Resolve { outcome, scalar_value } = vote {
+        require!(
+            clock.unix_timestamp >= market.resolution_timestamp,
+            DegenBetsError::MarketNotReady
+        );
+        market.check_resolution(outcome, scalar_value)?;
+    }
+
+    let committee = &ctx.accounts.committee;
+    let votes = &mut ctx.accounts.votes;
+    if votes.market == Pubkey::default() {
+        votes.market = market.key();
+        votes.bump = ctx.bumps.votes;
+        votes.payer = ctx.accounts.member.key();
+    }
+    votes.cast(committee, ctx.accounts.member.key(), vote.clone());
+    let votes_for = votes.tally(committee, &vote);
+
+    emit!(ResolutionVoteCast {
+        market: market.key(),
+        voter: ctx.accounts.member.key(),
+        vote: vote.clone(),
+        votes_for,
+        threshold: committee.threshold,
+    });
+
+    if votes_for < committee.threshold {
+        return Ok(());
+    }
+
+    match vote {
+        CommitteeVote::Resolve { outcome, scalar_value } => resolve_market::settle(
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.creator_profile,
+            outcome,
+            scalar_value,
+            Pubkey::default(),
+            clock.unix_timestamp,
+        ),
+        CommitteeVote::Void { reason_code } => void_market::void(
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.creator_profile,
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.creator_bond.to_account_info(),
+            "Voided by resolver committee".to_string(),
+            reason_code,
+        ),
+    }
+}
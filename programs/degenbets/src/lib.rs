@@ -7,13 +7,18 @@ pub mod instructions;
 pub mod errors;
 pub mod events;
 pub mod math;
+pub mod collateral;
+pub mod oracle;
+pub mod migration;
 
 use instructions::*;
+use state::{AdminRole, CommitteeVote, ConfigParams, OracleCondition, VoidReason};
 
 #[program]
 pub mod degenbets {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         ctx: Context<Initialize>,
         treasury: Pubkey,
@@ -48,24 +53,188 @@ pub mod degenbets {
         resolution_source: String,
         resolution_timestamp: i64,
         liquidity_amount: u64,
+        oracle: Option<OracleCondition>,
+        resolver: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::create_market::handler(ctx, question, resolution_source, resolution_timestamp, liquidity_amount)
+        instructions::create_market::handler(
+            ctx,
+            question,
+            resolution_source,
+            resolution_timestamp,
+            liquidity_amount,
+            oracle,
+            resolver,
+        )
     }
 
-    pub fn buy(ctx: Context<Buy>, amount: u64, side: bool) -> Result<()> {
-        instructions::buy::handler(ctx, amount, side)
+    pub fn create_categorical_market(
+        ctx: Context<CreateCategoricalMarket>,
+        question: String,
+        resolution_source: String,
+        resolution_timestamp: i64,
+        liquidity_amount: u64,
+        outcomes: Vec<String>,
+        resolver: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_categorical_market::handler(
+            ctx,
+            question,
+            resolution_source,
+            resolution_timestamp,
+            liquidity_amount,
+            outcomes,
+            resolver,
+        )
     }
 
-    pub fn sell(ctx: Context<Sell>, shares: u64, side: bool) -> Result<()> {
-        instructions::sell::handler(ctx, shares, side)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_scalar_market(
+        ctx: Context<CreateMarket>,
+        question: String,
+        resolution_source: String,
+        resolution_timestamp: i64,
+        liquidity_amount: u64,
+        lower_bound: i64,
+        upper_bound: i64,
+        oracle: Option<OracleCondition>,
+        resolver: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_scalar_market::handler(
+            ctx,
+            question,
+            resolution_source,
+            resolution_timestamp,
+            liquidity_amount,
+            lower_bound,
+            upper_bound,
+            oracle,
+            resolver,
+        )
     }
 
-    pub fn resolve_market(ctx: Context<ResolveMarket>, outcome: bool) -> Result<()> {
-        instructions::resolve_market::handler(ctx, outcome)
+    pub fn buy(
+        ctx: Context<Buy>,
+        amount: u64,
+        side: bool,
+        min_shares_out: u64,
+        max_price_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::buy::handler(ctx, amount, side, min_shares_out, max_price_bps)
     }
 
-    pub fn void_market(ctx: Context<VoidMarket>, reason: String) -> Result<()> {
-        instructions::void_market::handler(ctx, reason)
+    pub fn sell(
+        ctx: Context<Sell>,
+        shares: u64,
+        side: bool,
+        min_sol_out: u64,
+        min_price_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::sell::handler(ctx, shares, side, min_sol_out, min_price_bps)
+    }
+
+    pub fn buy_exact_shares(
+        ctx: Context<Buy>,
+        shares: u64,
+        side: bool,
+        max_sol_in: u64,
+    ) -> Result<()> {
+        instructions::buy_exact_shares::handler(ctx, shares, side, max_sol_in)
+    }
+
+    pub fn sell_for_exact_sol(
+        ctx: Context<Sell>,
+        sol_out: u64,
+        side: bool,
+        max_shares_in: u64,
+    ) -> Result<()> {
+        instructions::sell_for_exact_sol::handler(ctx, sol_out, side, max_shares_in)
+    }
+
+    pub fn buy_outcome(
+        ctx: Context<BuyOutcome>,
+        amount: u64,
+        outcome_index: u8,
+        min_shares_out: u64,
+    ) -> Result<()> {
+        instructions::buy_outcome::handler(ctx, amount, outcome_index, min_shares_out)
+    }
+
+    pub fn sell_outcome(
+        ctx: Context<SellOutcome>,
+        shares: u64,
+        outcome_index: u8,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        instructions::sell_outcome::handler(ctx, shares, outcome_index, min_sol_out)
+    }
+
+    pub fn split(ctx: Context<Split>, amount: u64) -> Result<()> {
+        instructions::split::handler(ctx, amount)
+    }
+
+    pub fn merge(ctx: Context<Merge>, pairs: u64) -> Result<()> {
+        instructions::merge::handler(ctx, pairs)
+    }
+
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        instructions::add_liquidity::handler(ctx, amount)
+    }
+
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, lp_shares: u64) -> Result<()> {
+        instructions::remove_liquidity::handler(ctx, lp_shares)
+    }
+
+    pub fn claim_liquidity(ctx: Context<ClaimLiquidity>) -> Result<()> {
+        instructions::claim_liquidity::handler(ctx)
+    }
+
+    pub fn resolve_market(
+        ctx: Context<ResolveMarket>,
+        outcome: u8,
+        scalar_value: Option<i64>,
+    ) -> Result<()> {
+        instructions::resolve_market::handler(ctx, outcome, scalar_value)
+    }
+
+    pub fn resolve_with_oracle(ctx: Context<ResolveWithOracle>) -> Result<()> {
+        instructions::resolve_with_oracle::handler(ctx)
+    }
+
+    pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+        instructions::cancel_market::handler(ctx)
+    }
+
+    pub fn void_market(
+        ctx: Context<VoidMarket>,
+        reason: String,
+        reason_code: VoidReason,
+    ) -> Result<()> {
+        instructions::void_market::handler(ctx, reason, reason_code)
+    }
+
+    pub fn set_resolver_committee(
+        ctx: Context<SetResolverCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_resolver_committee::handler(ctx, members, threshold)
+    }
+
+    pub fn vote_resolution(ctx: Context<VoteResolution>, vote: CommitteeVote) -> Result<()> {
+        instructions::vote_resolution::handler(ctx, vote)
+    }
+
+    pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+        instructions::dispute_resolution::handler(ctx)
+    }
+
+    pub fn settle_dispute(
+        ctx: Context<SettleDispute>,
+        corrected_outcome: Option<u8>,
+        corrected_value: Option<i64>,
+        void_reason: Option<VoidReason>,
+    ) -> Result<()> {
+        instructions::settle_dispute::handler(ctx, corrected_outcome, corrected_value, void_reason)
     }
 
     pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
@@ -80,40 +249,69 @@ pub mod degenbets {
         instructions::claim_refund::handler(ctx)
     }
 
+    pub fn claim_outcome_winnings(ctx: Context<ClaimOutcomeWinnings>) -> Result<()> {
+        instructions::claim_outcome_winnings::handler(ctx)
+    }
+
+    pub fn claim_outcome_refund(ctx: Context<ClaimOutcomeRefund>) -> Result<()> {
+        instructions::claim_outcome_refund::handler(ctx)
+    }
+
     pub fn claim_treasury_fee(ctx: Context<ClaimTreasuryFee>) -> Result<()> {
         instructions::claim_treasury_fee::handler(ctx)
     }
 
-    pub fn toggle_pause(ctx: Context<TogglePause>) -> Result<()> {
-        instructions::toggle_pause::handler(ctx)
+    pub fn toggle_pause(ctx: Context<TogglePause>, flags: u8) -> Result<()> {
+        instructions::toggle_pause::handler(ctx, flags)
+    }
+
+    pub fn toggle_market_pause(ctx: Context<ToggleMarketPause>) -> Result<()> {
+        instructions::toggle_market_pause::handler(ctx)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    pub fn queue_config_change(ctx: Context<QueueConfigChange>, params: ConfigParams) -> Result<()> {
+        instructions::queue_config_change::handler(ctx, params)
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change::handler(ctx)
     }
 
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        treasury: Option<Pubkey>,
-        min_liquidity_lamports: Option<u64>,
-        treasury_rake_bps: Option<u16>,
-        creator_rake_bps: Option<u16>,
-        min_trade_lamports: Option<u64>,
-        betting_cutoff_seconds: Option<i64>,
-        challenge_period_seconds: Option<i64>,
-        swap_fee_bps: Option<u16>,
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change::handler(ctx)
+    }
+
+    pub fn set_collateral_mint(
+        ctx: Context<SetCollateralMint>,
+        mint: Pubkey,
+        min_liquidity: u64,
+        min_trade: u64,
     ) -> Result<()> {
-        instructions::update_config::handler(
-            ctx,
-            treasury,
-            min_liquidity_lamports,
-            treasury_rake_bps,
-            creator_rake_bps,
-            min_trade_lamports,
-            betting_cutoff_seconds,
-            challenge_period_seconds,
-            swap_fee_bps,
-        )
+        instructions::set_collateral_mint::handler(ctx, mint, min_liquidity, min_trade)
+    }
+
+    pub fn remove_collateral_mint(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_collateral_mint::handler(ctx, mint)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx)
     }
 
-    pub fn transfer_authority(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
-        instructions::transfer_authority::handler(ctx, new_authority)
+    pub fn set_role(ctx: Context<SetRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+        instructions::set_role::handler(ctx, role, holder)
     }
 
     pub fn reclaim_stale_market(ctx: Context<ReclaimStaleMarket>) -> Result<()> {
@@ -127,4 +325,28 @@ pub mod degenbets {
     pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
         instructions::close_position::handler(ctx)
     }
+
+    pub fn close_outcome_position(ctx: Context<CloseOutcomePosition>) -> Result<()> {
+        instructions::close_outcome_position::handler(ctx)
+    }
+
+    pub fn close_resolution_votes(ctx: Context<CloseResolutionVotes>) -> Result<()> {
+        instructions::close_resolution_votes::handler(ctx)
+    }
+
+    pub fn migrate_creator_profile(ctx: Context<MigrateCreatorProfile>) -> Result<()> {
+        instructions::migrate_creator_profile::handler(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        instructions::migrate_market::handler(ctx)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        instructions::migrate_position::handler(ctx)
+    }
 }
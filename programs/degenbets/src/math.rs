@@ -7,7 +7,7 @@ pub fn isqrt(n: u128) -> u128 {
         return 0;
     }
     let mut x = n;
-    let mut y = (x + 1) / 2;
+    let mut y = x.div_ceil(2);
     while y < x {
         x = y;
         y = (x + n / x) / 2;
@@ -145,14 +145,20 @@ pub fn calc_sell_yes(
         .ok_or(DegenBetsError::MathOverflow)?;
     let sol_out = sol_before_fee.checked_sub(fee).ok_or(DegenBetsError::MathOverflow)?;
 
-    // Update reserves: user swaps A YES into pool, gets (S - A) NO from pool
-    let new_ry = ry.checked_add(a).ok_or(DegenBetsError::MathOverflow)?;
+    // Update reserves: user swaps A YES into pool, gets B = (S - A) NO from pool.
+    // Only sol_out of the B complete sets are burned; the fee sets stay in the
+    // pool as fee YES + fee NO, so total_minted -= sol_out remains exact.
     let b = s.checked_sub(a).ok_or(DegenBetsError::MathOverflow)?;
-    let new_rn = rn.checked_sub(b).ok_or(DegenBetsError::MathOverflow)?;
-
-    // total_minted decreases by sol_before_fee (complete sets burned, fee stays in vault)
-    // But the fee portion stays in the pool by NOT being paid out
-    // The caller handles total_minted -= sol_out and vault -= sol_out
+    let new_ry = ry
+        .checked_add(a)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .checked_add(fee)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let new_rn = rn
+        .checked_sub(b)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .checked_add(fee)
+        .ok_or(DegenBetsError::MathOverflow)?;
 
     Ok((sol_out as u64, new_ry as u64, new_rn as u64))
 }
@@ -168,6 +174,254 @@ pub fn calc_sell_no(
     Ok((sol_out, new_yes, new_no))
 }
 
+/// Bound on the correction steps applied after the closed-form inverses below.
+/// The quadratic estimate is off by at most a few lamports due to flooring.
+const MAX_INVERSE_ADJUST_STEPS: u32 = 16;
+
+/// Inverse of calc_buy_yes: SOL needed to receive at least `shares` YES.
+///
+/// With f = (10000 - fee) / 10000, calc_buy_yes gives
+///   T = s + f * Ry * s / (Rn + s)
+/// which rearranges to s^2 + s*(Rn + f*Ry - T) - T*Rn = 0.
+/// The closed-form root is then nudged against calc_buy_yes so the result is
+/// the smallest SOL amount whose exact-input fill reaches `shares`.
+///
+/// Returns (sol_in, new_yes_reserve, new_no_reserve)
+pub fn calc_buy_yes_exact_shares(
+    shares: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    swap_fee_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    require!(shares > 0, DegenBetsError::ZeroBetAmount);
+
+    let t = shares as u128;
+    let rn = no_reserve as u128;
+    let fy = (yes_reserve as u128)
+        .checked_mul(10000u128.saturating_sub(swap_fee_bps as u128))
+        .ok_or(DegenBetsError::MathOverflow)?
+        / 10000u128;
+
+    // disc = (Rn + f*Ry - T)^2 + 4*T*Rn
+    let four_t_rn = 4u128
+        .checked_mul(t)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .checked_mul(rn)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let rn_fy = rn.checked_add(fy).ok_or(DegenBetsError::MathOverflow)?;
+    let (b, b_negative) = if rn_fy >= t { (rn_fy - t, false) } else { (t - rn_fy, true) };
+    let disc = b
+        .checked_mul(b)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .checked_add(four_t_rn)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let sqrt_disc = isqrt(disc);
+
+    // s = (sqrt(disc) - b) / 2, rounded up
+    let numerator = if b_negative {
+        sqrt_disc.checked_add(b).ok_or(DegenBetsError::MathOverflow)?
+    } else {
+        sqrt_disc.saturating_sub(b)
+    };
+    let estimate = numerator.div_ceil(2).max(1);
+    require!(estimate <= u64::MAX as u128, DegenBetsError::MathOverflow);
+
+    let mut sol = estimate as u64;
+    let mut fill = calc_buy_yes(sol, yes_reserve, no_reserve, swap_fee_bps)?;
+    let mut steps = 0;
+    while fill.0 < shares {
+        require!(steps < MAX_INVERSE_ADJUST_STEPS, DegenBetsError::MathOverflow);
+        sol = sol.checked_add(1).ok_or(DegenBetsError::MathOverflow)?;
+        fill = calc_buy_yes(sol, yes_reserve, no_reserve, swap_fee_bps)?;
+        steps += 1;
+    }
+    while sol > 1 && steps < MAX_INVERSE_ADJUST_STEPS {
+        let smaller = calc_buy_yes(sol - 1, yes_reserve, no_reserve, swap_fee_bps)?;
+        if smaller.0 < shares {
+            break;
+        }
+        sol -= 1;
+        fill = smaller;
+        steps += 1;
+    }
+
+    Ok((sol, fill.1, fill.2))
+}
+
+/// Mirror of calc_buy_yes_exact_shares but for buying NO.
+pub fn calc_buy_no_exact_shares(
+    shares: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    swap_fee_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    let (sol, new_no, new_yes) = calc_buy_yes_exact_shares(shares, no_reserve, yes_reserve, swap_fee_bps)?;
+    Ok((sol, new_yes, new_no))
+}
+
+/// Inverse of calc_sell_yes: YES shares needed to receive at least `sol_out`.
+///
+/// The seller must burn B = sol_out / f complete sets, which requires swapping
+/// A YES into the pool for B NO: B = Rn * A / (Ry + A), so A = B * Ry / (Rn - B).
+/// Shares in = A + B, nudged against calc_sell_yes like the buy inverse.
+///
+/// Returns (shares_in, new_yes_reserve, new_no_reserve)
+pub fn calc_sell_yes_exact_sol(
+    sol_out: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    swap_fee_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    require!(sol_out > 0, DegenBetsError::ZeroBetAmount);
+    require!(swap_fee_bps < 10000, DegenBetsError::InvalidRakeBps);
+
+    let ry = yes_reserve as u128;
+    let rn = no_reserve as u128;
+
+    // Complete sets to burn before the fee is taken, rounded up
+    let b = (sol_out as u128)
+        .checked_mul(10000u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .div_ceil(10000u128 - swap_fee_bps as u128);
+    require!(b < rn, DegenBetsError::EmptyPool);
+
+    let a = b
+        .checked_mul(ry)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .div_ceil(rn - b);
+    let estimate = a.checked_add(b).ok_or(DegenBetsError::MathOverflow)?;
+    require!(estimate <= u64::MAX as u128, DegenBetsError::MathOverflow);
+
+    let mut shares = estimate as u64;
+    let mut fill = calc_sell_yes(shares, yes_reserve, no_reserve, swap_fee_bps)?;
+    let mut steps = 0;
+    while fill.0 < sol_out {
+        require!(steps < MAX_INVERSE_ADJUST_STEPS, DegenBetsError::MathOverflow);
+        shares = shares.checked_add(1).ok_or(DegenBetsError::MathOverflow)?;
+        fill = calc_sell_yes(shares, yes_reserve, no_reserve, swap_fee_bps)?;
+        steps += 1;
+    }
+    while shares > 1 && steps < MAX_INVERSE_ADJUST_STEPS {
+        let smaller = calc_sell_yes(shares - 1, yes_reserve, no_reserve, swap_fee_bps)?;
+        if smaller.0 < sol_out {
+            break;
+        }
+        shares -= 1;
+        fill = smaller;
+        steps += 1;
+    }
+
+    Ok((shares, fill.1, fill.2))
+}
+
+/// Mirror of calc_sell_yes_exact_sol but for selling NO.
+pub fn calc_sell_no_exact_sol(
+    sol_out: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    swap_fee_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    let (shares, new_no, new_yes) = calc_sell_yes_exact_sol(sol_out, no_reserve, yes_reserve, swap_fee_bps)?;
+    Ok((shares, new_yes, new_no))
+}
+
+/// Calculate LP shares minted and reserves added when depositing liquidity.
+///
+/// Mechanism: mint `amount` complete sets and add them to the pool at the
+/// current reserve ratio. The larger reserve receives the full `amount`, the
+/// smaller one proportionally less; the surplus shares of the smaller side's
+/// opposite go back to the provider so the price is unchanged.
+/// LP shares are minted pro-rata to the larger reserve (the pool weight).
+///
+/// Returns (lp_shares_minted, yes_added, no_added)
+pub fn calc_add_liquidity(
+    amount: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    total_lp_shares: u64,
+) -> Result<(u64, u64, u64)> {
+    require!(amount > 0, DegenBetsError::ZeroBetAmount);
+
+    let pool_weight = yes_reserve.max(no_reserve) as u128;
+    if pool_weight == 0 || total_lp_shares == 0 {
+        // Empty pool: seed it 50/50
+        return Ok((amount, amount, amount));
+    }
+
+    let amt = amount as u128;
+    let yes_added = amt
+        .checked_mul(yes_reserve as u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / pool_weight;
+    let no_added = amt
+        .checked_mul(no_reserve as u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / pool_weight;
+    let lp_minted = amt
+        .checked_mul(total_lp_shares as u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / pool_weight;
+    require!(lp_minted > 0, DegenBetsError::ZeroBetAmount);
+
+    Ok((lp_minted as u64, yes_added as u64, no_added as u64))
+}
+
+/// Calculate the reserve shares released by burning `lp_shares`.
+///
+/// Returns (yes_out, no_out)
+pub fn calc_remove_liquidity(
+    lp_shares: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+    total_lp_shares: u64,
+) -> Result<(u64, u64)> {
+    require!(lp_shares > 0, DegenBetsError::ZeroBetAmount);
+    require!(lp_shares <= total_lp_shares, DegenBetsError::InsufficientShares);
+
+    let lp = lp_shares as u128;
+    let total = total_lp_shares as u128;
+    let yes_out = (yes_reserve as u128)
+        .checked_mul(lp)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / total;
+    let no_out = (no_reserve as u128)
+        .checked_mul(lp)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / total;
+
+    Ok((yes_out as u64, no_out as u64))
+}
+
+/// SOL paid for `winning_shares` after resolution.
+///
+/// Every complete set is one winning share, so each winning share gets
+/// prize_pool / total_minted where prize_pool = total_minted - rake.
+pub fn calc_winning_payout(
+    winning_shares: u64,
+    total_minted: u64,
+    treasury_fee: u64,
+    creator_fee: u64,
+) -> Result<u64> {
+    if total_minted == 0 {
+        return Ok(0);
+    }
+
+    let total_rake = treasury_fee
+        .checked_add(creator_fee)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let prize_pool = total_minted
+        .checked_sub(total_rake)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    let payout = (winning_shares as u128)
+        .checked_mul(prize_pool as u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        .checked_div(total_minted as u128)
+        .ok_or(DegenBetsError::MathOverflow)?;
+
+    Ok(payout as u64)
+}
+
 /// Calculate price as basis points (0-10000) for events.
 /// price_yes = no_reserve / (yes_reserve + no_reserve) * 10000
 pub fn price_yes_bps(yes_reserve: u64, no_reserve: u64) -> u64 {
@@ -180,6 +434,257 @@ pub fn price_yes_bps(yes_reserve: u64, no_reserve: u64) -> u64 {
     (rn * 10000 / total) as u64
 }
 
+/// Price of the given side as basis points (0-10000), used for slippage bounds.
+pub fn side_price_bps(yes_reserve: u64, no_reserve: u64, side: bool) -> u64 {
+    let price_yes = price_yes_bps(yes_reserve, no_reserve);
+    if side {
+        price_yes
+    } else {
+        10000 - price_yes
+    }
+}
+
+/// Collateral value of YES and NO shares at the pool price, per side.
+/// Used as the cost basis of shares moved between the pool and a position.
+pub fn calc_shares_value(
+    yes_shares: u64,
+    no_shares: u64,
+    yes_reserve: u64,
+    no_reserve: u64,
+) -> (u64, u64) {
+    let price_yes = price_yes_bps(yes_reserve, no_reserve) as u128;
+    let yes_value = yes_shares as u128 * price_yes / 10000;
+    let no_value = no_shares as u128 * (10000 - price_yes) / 10000;
+    (yes_value as u64, no_value as u64)
+}
+
+// --- Categorical markets: N-outcome CPMM (invariant: product of all reserves) ---
+//
+// Products of up to 16 reserves overflow u128, so every invariant step is
+// applied as a running ratio, one reserve at a time, rounding in the pool's favour.
+
+/// Fixed-point scale for inverse reserves when computing outcome prices.
+const PRICE_SCALE: u128 = 1 << 96;
+
+/// Reserve `index` must hold after every other reserve moves from `reserves[j]`
+/// to `others[j]`, keeping the product of reserves constant (rounded up).
+fn required_reserve(reserves: &[u64], others: &[u128], index: usize) -> Result<u128> {
+    let mut target = reserves[index] as u128;
+    for (j, &r) in reserves.iter().enumerate() {
+        if j == index {
+            continue;
+        }
+        require!(others[j] > 0, DegenBetsError::EmptyPool);
+        target = target
+            .checked_mul(r as u128)
+            .ok_or(DegenBetsError::MathOverflow)?
+            .div_ceil(others[j]);
+    }
+    Ok(target)
+}
+
+/// Calculate shares received when buying one outcome of a categorical market.
+///
+/// Mechanism mirrors calc_buy_yes: mint `sol_amount` complete sets, swap every
+/// unwanted outcome into the pool and take the wanted outcome out.
+///
+/// Returns (shares_out, new_reserves)
+pub fn calc_buy_outcome(
+    sol_amount: u64,
+    reserves: &[u64],
+    index: usize,
+    swap_fee_bps: u16,
+) -> Result<(u64, Vec<u64>)> {
+    require!(index < reserves.len(), DegenBetsError::InvalidOutcomeIndex);
+    let sol = sol_amount as u128;
+
+    let others = reserves
+        .iter()
+        .map(|&r| (r as u128).checked_add(sol).ok_or(DegenBetsError::MathOverflow.into()))
+        .collect::<Result<Vec<u128>>>()?;
+
+    let ri = reserves[index] as u128;
+    let ri_after_swap = required_reserve(reserves, &others, index)?;
+    let out_raw = ri.saturating_sub(ri_after_swap);
+
+    // Fee on swap output stays in pool
+    let fee = out_raw * swap_fee_bps as u128 / 10000u128;
+    let out = out_raw - fee;
+
+    let mut new_reserves = Vec::with_capacity(reserves.len());
+    for (j, other) in others.iter().enumerate() {
+        let r = if j == index { ri - out } else { *other };
+        new_reserves.push(u64::try_from(r).map_err(|_| DegenBetsError::MathOverflow)?);
+    }
+
+    let total_shares = sol.checked_add(out).ok_or(DegenBetsError::MathOverflow)?;
+    Ok((
+        u64::try_from(total_shares).map_err(|_| DegenBetsError::MathOverflow)?,
+        new_reserves,
+    ))
+}
+
+/// Calculate collateral received when selling one outcome of a categorical market.
+///
+/// Mechanism mirrors calc_sell_yes: swap part of the shares into the pool for
+/// `c` of every other outcome, then burn `c` complete sets. With N outcomes
+/// there is no closed form, so `c` is found by binary search on the invariant.
+///
+/// Returns (sol_out, new_reserves)
+pub fn calc_sell_outcome(
+    shares: u64,
+    reserves: &[u64],
+    index: usize,
+    swap_fee_bps: u16,
+) -> Result<(u64, Vec<u64>)> {
+    require!(shares > 0, DegenBetsError::ZeroBetAmount);
+    require!(index < reserves.len(), DegenBetsError::InvalidOutcomeIndex);
+
+    let s = shares as u128;
+    let ri = reserves[index] as u128;
+
+    // Largest c the pool can release: bounded by the shares sold and by
+    // every other reserve (which must stay non-empty)
+    let mut hi = s;
+    for (j, &r) in reserves.iter().enumerate() {
+        if j != index {
+            hi = hi.min((r as u128).saturating_sub(1));
+        }
+    }
+
+    // Sets burned for a given c keep the invariant iff ri + s - c >= required
+    let feasible = |c: u128| -> Result<bool> {
+        let others = reserves.iter().map(|&r| r as u128 - c.min(r as u128)).collect::<Vec<u128>>();
+        Ok(ri + s - c >= required_reserve(reserves, &others, index)?)
+    };
+
+    let mut lo = 0u128;
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if feasible(mid)? {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    let sets = lo;
+
+    // Apply swap fee to the output; fee sets stay in the pool
+    let fee = sets * swap_fee_bps as u128 / 10000u128;
+    let sol_out = sets - fee;
+
+    let mut new_reserves = Vec::with_capacity(reserves.len());
+    for (j, &r) in reserves.iter().enumerate() {
+        let r = if j == index {
+            ri + s - sets + fee
+        } else {
+            r as u128 - sets + fee
+        };
+        new_reserves.push(u64::try_from(r).map_err(|_| DegenBetsError::MathOverflow)?);
+    }
+
+    Ok((sol_out as u64, new_reserves))
+}
+
+/// Price vector of a categorical pool in basis points.
+///
+/// price_i = (1 / r_i) / sum_j (1 / r_j); for two outcomes this reduces to
+/// price_yes_bps. Empty reserves price at a uniform 1/N.
+pub fn outcome_prices_bps(reserves: &[u64]) -> Vec<u64> {
+    outcome_prices(reserves, 10000)
+}
+
+/// Pool price vector scaled to `scale`, rounded down so the prices never sum
+/// past `scale`.
+fn outcome_prices(reserves: &[u64], scale: u128) -> Vec<u64> {
+    let n = reserves.len() as u128;
+    if n == 0 {
+        return Vec::new();
+    }
+    if reserves.contains(&0) {
+        return vec![(scale / n) as u64; reserves.len()];
+    }
+
+    let inverses: Vec<u128> = reserves.iter().map(|&r| PRICE_SCALE / r as u128).collect();
+    let total: u128 = inverses.iter().sum();
+    inverses
+        .iter()
+        .map(|&inv| (inv * scale / total) as u64)
+        .collect()
+}
+
+// --- Void settlement: shares are valued at the pool price when voided ---
+
+/// Fixed-point precision for the outcome prices snapshotted on void.
+pub const VOID_PRICE_PRECISION: u128 = 1_000_000_000;
+
+/// Outcome prices a voided market settles at, in `VOID_PRICE_PRECISION`
+/// units, indexed like `reserves` (binary: `[no_reserve, yes_reserve]`).
+pub fn void_prices(reserves: &[u64]) -> Vec<u64> {
+    outcome_prices(reserves, VOID_PRICE_PRECISION)
+}
+
+/// Refund for shares in a voided market, each valued at its outcome's
+/// snapshotted price.
+///
+/// Every outcome has exactly total_minted shares outstanding across holders
+/// and the pool, and the prices sum to at most one set, so refunds summed over
+/// everyone never exceed total_minted.
+pub fn calc_void_refund(shares: &[u64], prices: &[u64]) -> Result<u64> {
+    require!(shares.len() == prices.len(), DegenBetsError::InvalidOutcomeIndex);
+    let value = shares
+        .iter()
+        .zip(prices)
+        .try_fold(0u128, |acc, (&s, &p)| {
+            acc.checked_add((s as u128).checked_mul(p as u128)?)
+        })
+        .ok_or(DegenBetsError::MathOverflow)?;
+    Ok((value / VOID_PRICE_PRECISION) as u64)
+}
+
+// --- Scalar markets: YES/NO pool shares act as LONG/SHORT ---
+
+/// Fixed-point precision for a scalar market's LONG payout fraction.
+pub const SCALAR_PRECISION: u128 = 1_000_000_000;
+
+/// Fraction of each complete set paid to LONG when a scalar market resolves
+/// at `value`, clamped to the bounds, in `SCALAR_PRECISION` units.
+pub fn scalar_long_fraction(value: i64, lower_bound: i64, upper_bound: i64) -> Result<u128> {
+    require!(upper_bound > lower_bound, DegenBetsError::InvalidScalarBounds);
+    let v = value.clamp(lower_bound, upper_bound);
+    let num = (v as i128 - lower_bound as i128) as u128;
+    let den = (upper_bound as i128 - lower_bound as i128) as u128;
+    Ok(num * SCALAR_PRECISION / den)
+}
+
+/// Set-equivalent value of a scalar position: each LONG share is worth
+/// `long_fraction` of a set, each SHORT share the remainder. Summed over every
+/// holder (and the pool) this equals total_minted.
+pub fn calc_scalar_settlement(long_shares: u64, short_shares: u64, long_fraction: u128) -> Result<u64> {
+    let long_value = (long_shares as u128)
+        .checked_mul(long_fraction)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    let short_value = (short_shares as u128)
+        .checked_mul(SCALAR_PRECISION - long_fraction)
+        .ok_or(DegenBetsError::MathOverflow)?;
+    Ok(((long_value + short_value) / SCALAR_PRECISION) as u64)
+}
+
+// --- Position cost basis ---
+
+/// Cost basis released when `disposed` of `held` shares leave a position,
+/// at the position's average cost per share.
+pub fn calc_released_cost(open_cost: u64, disposed: u64, held: u64) -> Result<u64> {
+    if held == 0 {
+        return Ok(open_cost);
+    }
+    let released = (open_cost as u128)
+        .checked_mul(disposed.min(held) as u128)
+        .ok_or(DegenBetsError::MathOverflow)?
+        / held as u128;
+    Ok(released as u64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +734,284 @@ mod tests {
         let price = price_yes_bps(new_ry, new_rn);
         assert!(price > 5000);
     }
+
+    #[test]
+    fn test_buy_exact_shares_inverse() {
+        let ry = 1_000_000_000u64;
+        let rn = 1_500_000_000u64;
+        let fee = 30u16;
+
+        for target in [1u64, 1_000, 10_000_000, 250_000_000, 3_000_000_000] {
+            let (sol, new_ry, new_rn) = calc_buy_yes_exact_shares(target, ry, rn, fee).unwrap();
+            let (shares, fwd_ry, fwd_rn) = calc_buy_yes(sol, ry, rn, fee).unwrap();
+            assert!(shares >= target);
+            assert_eq!((new_ry, new_rn), (fwd_ry, fwd_rn));
+            // Minimal input: one lamport less falls short
+            let (fewer, _, _) = calc_buy_yes(sol - 1, ry, rn, fee).unwrap();
+            assert!(sol == 1 || fewer < target);
+
+            let (sol_no, _, _) = calc_buy_no_exact_shares(target, ry, rn, fee).unwrap();
+            let (shares_no, _, _) = calc_buy_no(sol_no, ry, rn, fee).unwrap();
+            assert!(shares_no >= target);
+        }
+    }
+
+    #[test]
+    fn test_sell_exact_sol_inverse() {
+        let ry = 1_200_000_000u64;
+        let rn = 800_000_000u64;
+        let fee = 30u16;
+
+        for target in [1u64, 1_000, 10_000_000, 300_000_000] {
+            let (shares, new_ry, new_rn) = calc_sell_yes_exact_sol(target, ry, rn, fee).unwrap();
+            let (sol, fwd_ry, fwd_rn) = calc_sell_yes(shares, ry, rn, fee).unwrap();
+            assert!(sol >= target);
+            assert_eq!((new_ry, new_rn), (fwd_ry, fwd_rn));
+            let (less, _, _) = calc_sell_yes(shares - 1, ry, rn, fee).unwrap_or((0, 0, 0));
+            assert!(shares == 1 || less < target);
+
+            let (shares_no, _, _) = calc_sell_no_exact_sol(target, ry, rn, fee).unwrap();
+            let (sol_no, _, _) = calc_sell_no(shares_no, ry, rn, fee).unwrap();
+            assert!(sol_no >= target);
+        }
+
+        // Cannot withdraw more complete sets than the opposite reserve holds
+        assert!(calc_sell_yes_exact_sol(rn, ry, rn, fee).is_err());
+    }
+
+    #[test]
+    fn test_complete_set_invariant() {
+        // Every outstanding YES and NO share is backed by one minted complete set:
+        // user shares + pool reserve == total_minted on both sides.
+        let liquidity = 1_000_000_000u64;
+        let fee = 30u16;
+        let (mut ry, mut rn, mut total) = (liquidity, liquidity, liquidity);
+        let (mut user_yes, mut user_no) = (0u64, 0u64);
+
+        let (shares, new_ry, new_rn) = calc_buy_yes(300_000_000, ry, rn, fee).unwrap();
+        (ry, rn, total) = (new_ry, new_rn, total + 300_000_000);
+        user_yes += shares;
+        assert_eq!(user_yes + ry, total);
+        assert_eq!(user_no + rn, total);
+
+        // Split 0.1 SOL into a complete set
+        total += 100_000_000;
+        user_yes += 100_000_000;
+        user_no += 100_000_000;
+        assert_eq!(user_yes + ry, total);
+        assert_eq!(user_no + rn, total);
+
+        let (sol_out, new_ry, new_rn) = calc_sell_yes(user_yes / 2, ry, rn, fee).unwrap();
+        user_yes -= user_yes / 2;
+        (ry, rn, total) = (new_ry, new_rn, total - sol_out);
+        assert_eq!(user_yes + ry, total);
+        assert_eq!(user_no + rn, total);
+
+        let (sol_out, new_ry, new_rn) = calc_sell_no(user_no, ry, rn, fee).unwrap();
+        user_no = 0;
+        (ry, rn, total) = (new_ry, new_rn, total - sol_out);
+        assert_eq!(user_yes + ry, total);
+        assert_eq!(user_no + rn, total);
+    }
+
+    #[test]
+    fn test_add_remove_liquidity() {
+        // Skewed pool: YES is 60%
+        let ry = 800_000_000u64;
+        let rn = 1_200_000_000u64;
+        let total_lp = 1_000_000_000u64;
+        let price_before = price_yes_bps(ry, rn);
+
+        let (lp, add_y, add_n) = calc_add_liquidity(600_000_000, ry, rn, total_lp).unwrap();
+        // Larger reserve receives the full amount, LP minted against pool weight
+        assert_eq!(add_n, 600_000_000);
+        assert_eq!(add_y, 400_000_000);
+        assert_eq!(lp, 500_000_000);
+        assert_eq!(price_yes_bps(ry + add_y, rn + add_n), price_before);
+
+        // Burning the fresh LP shares returns exactly what was added
+        let (yes_out, no_out) = calc_remove_liquidity(lp, ry + add_y, rn + add_n, total_lp + lp).unwrap();
+        assert_eq!((yes_out, no_out), (add_y, add_n));
+
+        assert!(calc_remove_liquidity(total_lp + 1, ry, rn, total_lp).is_err());
+    }
+
+    #[test]
+    fn test_winning_payout() {
+        // 2 SOL pot, 6.5% rake: each winning share pays 0.935
+        let payout = calc_winning_payout(1_000_000_000, 2_000_000_000, 100_000_000, 30_000_000).unwrap();
+        assert_eq!(payout, 935_000_000);
+        assert_eq!(calc_winning_payout(5, 0, 0, 0).unwrap(), 0);
+
+    }
+
+    #[test]
+    fn test_overturned_winners_all_claim() {
+        // Trade both sides, resolve YES, then overturn to NO on dispute
+        let liquidity = 1_000_000_000u64;
+        // Trader A buys YES then splits, so A holds some NO as well
+        let (_, ry, rn) = calc_buy_yes(600_000_000, liquidity, liquidity, 30).unwrap();
+        let (no_b, _, rn) = calc_buy_no(400_000_000, ry, rn, 30).unwrap();
+        let total_minted = liquidity + 600_000_000 + 400_000_000 + 200_000_000;
+        let no_a = total_minted - rn - no_b;
+
+        // Fees are booked at resolution and survive the overturn
+        let treasury_fee = total_minted * 500 / 10_000;
+        let creator_fee = total_minted * 150 / 10_000;
+        let vault = total_minted;
+
+        // Every NO holder claims, the pool's NO reserve included (LPs)
+        let claimed: u64 = [no_a, no_b, rn]
+            .iter()
+            .map(|&shares| calc_winning_payout(shares, total_minted, treasury_fee, creator_fee).unwrap())
+            .sum();
+        assert!(claimed + treasury_fee + creator_fee <= vault);
+        assert!(vault - claimed - treasury_fee - creator_fee <= 3);
+
+        // Paying the creator fee out of the vault and then dropping it from the
+        // rake would leave the last winners unpaid
+        let without_fee: u64 = [no_a, no_b, rn]
+            .iter()
+            .map(|&shares| calc_winning_payout(shares, total_minted, treasury_fee, 0).unwrap())
+            .sum();
+        assert!(without_fee + treasury_fee + creator_fee > vault);
+    }
+
+    #[test]
+    fn test_side_price_bps() {
+        let (_, new_ry, new_rn) = calc_buy_yes(500_000_000, 1_000_000_000, 1_000_000_000, 30).unwrap();
+        let yes = side_price_bps(new_ry, new_rn, true);
+        let no = side_price_bps(new_ry, new_rn, false);
+        assert_eq!(yes, price_yes_bps(new_ry, new_rn));
+        assert_eq!(yes + no, 10000);
+    }
+
+    #[test]
+    fn test_categorical_buy_sell_roundtrip() {
+        let reserves = vec![1_000_000_000u64; 4];
+        let fee = 30u16;
+
+        let (shares, after_buy) = calc_buy_outcome(100_000_000, &reserves, 2, fee).unwrap();
+        assert!(shares > 100_000_000);
+        assert!(after_buy[2] < reserves[2]);
+        for j in [0, 1, 3] {
+            assert_eq!(after_buy[j], reserves[j] + 100_000_000);
+        }
+
+        // Invariant never decreases
+        let product = |r: &[u64]| r.iter().map(|&x| x as f64).product::<f64>();
+        assert!(product(&after_buy) >= product(&reserves));
+
+        let prices = outcome_prices_bps(&after_buy);
+        assert!(prices[2] > 2500);
+        assert!(prices[0] < 2500);
+
+        let (sol_back, after_sell) = calc_sell_outcome(shares, &after_buy, 2, fee).unwrap();
+        assert!(sol_back < 100_000_000);
+        assert!(sol_back > 99_000_000);
+        assert!(product(&after_sell) >= product(&after_buy));
+
+        // Complete-set invariant: every outcome's pool + user supply equals sets minted
+        let minted = 1_000_000_000u64 + 100_000_000 - sol_back;
+        assert!(after_sell.iter().all(|&r| r == minted));
+    }
+
+    #[test]
+    fn test_outcome_prices_bps() {
+        assert_eq!(outcome_prices_bps(&[5, 5, 5]), vec![3333, 3333, 3333]);
+        assert_eq!(outcome_prices_bps(&[0, 7, 7, 7]), vec![2500; 4]);
+
+        let prices = outcome_prices_bps(&[1_000_000_000, 3_000_000_000, 2_000_000_000]);
+        assert!(prices[0] > prices[2] && prices[2] > prices[1]);
+        assert!(prices.iter().sum::<u64>() >= 9998);
+
+        // Two outcomes reduce to the binary YES price
+        let ry = 700_000_000u64;
+        let rn = 1_300_000_000u64;
+        let binary = outcome_prices_bps(&[ry, rn]);
+        assert!(binary[0].abs_diff(price_yes_bps(ry, rn)) <= 1);
+    }
+
+    #[test]
+    fn test_void_refund_at_pool_price() {
+        // Balanced pool: every share is worth half a set
+        let prices = void_prices(&[1_000, 1_000]);
+        assert_eq!(calc_void_refund(&[100, 300], &prices).unwrap(), 200);
+
+        // YES bid up to 80%: YES holders get 0.8 per share, not 0.5
+        let prices = void_prices(&[4_096, 1_024]);
+        assert_eq!(prices, vec![200_000_000, 800_000_000]);
+        assert_eq!(calc_void_refund(&[0, 1_000], &prices).unwrap(), 800);
+        assert_eq!(calc_void_refund(&[1_000, 0], &prices).unwrap(), 200);
+        // A complete set is still worth one set
+        assert_eq!(calc_void_refund(&[1_000, 1_000], &prices).unwrap(), 1_000);
+
+        let prices = void_prices(&[100, 100, 100]);
+        assert_eq!(calc_void_refund(&[300, 0, 0], &prices).unwrap(), 99);
+        assert_eq!(calc_void_refund(&[], &[]).unwrap(), 0);
+        assert!(calc_void_refund(&[1, 2], &prices).is_err());
+    }
+
+    #[test]
+    fn test_void_refunds_never_exceed_collateral() {
+        // Trade a binary pool, then split every outcome's supply between
+        // traders and the pool the way positions would hold it
+        let liquidity = 1_000_000_000u64;
+        let (yes_a, ry, rn) = calc_buy_yes(700_000_000, liquidity, liquidity, 30).unwrap();
+        let total_minted = liquidity + 700_000_000;
+        let (no_b, ry, rn) = calc_buy_no(250_000_000, ry, rn, 30).unwrap();
+        let total_minted = total_minted + 250_000_000;
+        let yes_b = total_minted - ry - yes_a;
+        let no_a = total_minted - rn - no_b;
+
+        let prices = void_prices(&[rn, ry]);
+        let holdings = [[no_a, yes_a], [no_b, yes_b], [rn, ry]];
+        let refunded: u64 = holdings
+            .iter()
+            .map(|h| calc_void_refund(h, &prices).unwrap())
+            .sum();
+        assert!(refunded <= total_minted);
+        // Only rounding dust stays behind
+        assert!(total_minted - refunded < total_minted / 1_000_000);
+
+        // Categorical: any split of the supply stays within total_minted
+        let reserves = [333u64, 1_000_000, 7];
+        let prices = void_prices(&reserves);
+        assert!(prices.iter().sum::<u64>() as u128 <= VOID_PRICE_PRECISION);
+        let total = 2_000_000u64;
+        let a: Vec<u64> = reserves.iter().map(|&r| (total - r) / 3).collect();
+        let b: Vec<u64> = reserves.iter().zip(&a).map(|(&r, &x)| total - r - x).collect();
+        let refunded = calc_void_refund(&a, &prices).unwrap()
+            + calc_void_refund(&b, &prices).unwrap()
+            + calc_void_refund(&reserves, &prices).unwrap();
+        assert!(refunded <= total);
+    }
+
+    #[test]
+    fn test_scalar_settlement() {
+        // Range 100..300, resolved at 150: LONG gets 25% of each set
+        let fraction = scalar_long_fraction(150, 100, 300).unwrap();
+        assert_eq!(fraction, SCALAR_PRECISION / 4);
+        assert_eq!(calc_scalar_settlement(1_000, 0, fraction).unwrap(), 250);
+        assert_eq!(calc_scalar_settlement(0, 1_000, fraction).unwrap(), 750);
+        assert_eq!(calc_scalar_settlement(1_000, 1_000, fraction).unwrap(), 1_000);
+
+        // Clamped at the bounds, including negative ranges
+        assert_eq!(scalar_long_fraction(-50, -10, 10).unwrap(), 0);
+        assert_eq!(scalar_long_fraction(i64::MAX, i64::MIN, i64::MAX).unwrap(), SCALAR_PRECISION);
+        assert!(scalar_long_fraction(5, 5, 5).is_err());
+    }
+
+    #[test]
+    fn test_shares_value_at_pool_price() {
+        // YES at 75%: 100 YES and 100 NO are worth a complete set between them
+        assert_eq!(calc_shares_value(100, 100, 1_000, 3_000), (75, 25));
+        assert_eq!(calc_shares_value(40, 0, 1_000, 3_000), (30, 0));
+
+        // Surplus shares from an add are never worth more than the deposit
+        let (lp_minted, yes_added, no_added) = calc_add_liquidity(1_000, 1_000, 3_000, 2_000).unwrap();
+        assert!(lp_minted > 0);
+        let (yes_value, no_value) = calc_shares_value(1_000 - yes_added, 1_000 - no_added, 1_000, 3_000);
+        assert!(yes_value + no_value <= 1_000);
+    }
 }
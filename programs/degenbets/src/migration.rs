@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::errors::DegenBetsError;
+
+// Accounts created before a layout change are grown in place by the
+// `migrate_*` instructions. Fields are only ever appended, so a legacy
+// account is its old serialization followed by zeroes once resized, which
+// decode as 0 / `None` / empty collections. Anything that must not start at
+// zero is set by the migrating instruction.
+
+/// Check `data` is a legacy account of the type with `discriminator`, still
+/// at `legacy_len` rather than the current `current_len`.
+pub fn check_legacy(
+    data: &[u8],
+    discriminator: &[u8],
+    legacy_len: usize,
+    current_len: usize,
+) -> Result<()> {
+    require!(data.starts_with(discriminator), DegenBetsError::InvalidMigrationAccount);
+    require!(data.len() != current_len, DegenBetsError::AccountAlreadyMigrated);
+    require!(data.len() == legacy_len, DegenBetsError::InvalidMigrationAccount);
+    Ok(())
+}
+
+/// Resize `account` to `new_len`, with `payer` covering the extra rent.
+/// Appended bytes are zeroed.
+pub fn grow<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use crate::errors::DegenBetsError;
+
+// Minimal reader for Pyth (v2) price accounts. Only the fields needed for
+// resolution are read, straight from the account data:
+//   0   magic      u32   (0xa1b2c3d4)
+//   8   atype      u32   (3 = price account)
+//   20  expo       i32
+//   96  timestamp  i64   (unix time of the aggregate price)
+//   200 prev_timestamp i64 (unix time of the previous aggregate price)
+//   208 agg.price  i64
+//   216 agg.conf   u64
+//   224 agg.status u32   (1 = trading)
+
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+pub const PYTH_PRICE_ACCOUNT: u32 = 3;
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const PREV_TIMESTAMP_OFFSET: usize = 200;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const MIN_PRICE_ACCOUNT_LEN: usize = 240;
+
+/// Oldest price (seconds before now) accepted for resolution
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+/// Latest publish time (seconds after the resolution timestamp) accepted for
+/// resolution. Later prices describe a different moment than the market asked
+/// about; such markets fall back to their resolver.
+pub const MAX_PUBLISH_DELAY_SECONDS: i64 = 60;
+/// Widest confidence interval accepted, relative to the price
+pub const MAX_CONFIDENCE_BPS: u64 = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub timestamp: i64,
+    pub prev_timestamp: i64,
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut out = [0u8; N];
+    out.copy_from_slice(&data[offset..offset + N]);
+    out
+}
+
+/// Parse the aggregate price out of a Pyth price account.
+pub fn parse_price_account(data: &[u8]) -> Result<OraclePrice> {
+    require!(data.len() >= MIN_PRICE_ACCOUNT_LEN, DegenBetsError::InvalidOracleAccount);
+    require!(
+        u32::from_le_bytes(read_bytes(data, 0)) == PYTH_MAGIC
+            && u32::from_le_bytes(read_bytes(data, 8)) == PYTH_PRICE_ACCOUNT,
+        DegenBetsError::InvalidOracleAccount
+    );
+    require!(
+        u32::from_le_bytes(read_bytes(data, AGG_STATUS_OFFSET)) == PYTH_STATUS_TRADING,
+        DegenBetsError::OraclePriceUnavailable
+    );
+
+    Ok(OraclePrice {
+        price: i64::from_le_bytes(read_bytes(data, AGG_PRICE_OFFSET)),
+        conf: u64::from_le_bytes(read_bytes(data, AGG_CONF_OFFSET)),
+        expo: i32::from_le_bytes(read_bytes(data, EXPO_OFFSET)),
+        timestamp: i64::from_le_bytes(read_bytes(data, TIMESTAMP_OFFSET)),
+        prev_timestamp: i64::from_le_bytes(read_bytes(data, PREV_TIMESTAMP_OFFSET)),
+    })
+}
+
+/// Accept only the first price published at or after `resolution_timestamp`
+/// (its previous aggregate predates it), within MAX_PUBLISH_DELAY_SECONDS of
+/// it, no older than MAX_PRICE_AGE_SECONDS, and with a tight enough
+/// confidence interval. Anyone resolving later can't pick a more favourable
+/// price from the window; once the feed moves on, the resolver settles it.
+pub fn check_price(price: &OraclePrice, resolution_timestamp: i64, now: i64) -> Result<()> {
+    require!(
+        price.timestamp >= resolution_timestamp && now - price.timestamp <= MAX_PRICE_AGE_SECONDS,
+        DegenBetsError::OraclePriceStale
+    );
+    require!(
+        price.timestamp - resolution_timestamp <= MAX_PUBLISH_DELAY_SECONDS,
+        DegenBetsError::OraclePriceTooLate
+    );
+    require!(
+        price.prev_timestamp < resolution_timestamp,
+        DegenBetsError::OraclePriceNotFirst
+    );
+    require!(price.price > 0, DegenBetsError::OraclePriceUnavailable);
+    require!(
+        (price.conf as u128) * 10000 <= (price.price as u128) * MAX_CONFIDENCE_BPS as u128,
+        DegenBetsError::OracleConfidenceTooWide
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_price_account(price: i64, conf: u64, expo: i32, timestamp: i64, status: u32) -> Vec<u8> {
+        let prev_timestamp = timestamp - 1;
+        let mut data = vec![0u8; 3312];
+        data[0..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[8..12].copy_from_slice(&PYTH_PRICE_ACCOUNT.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&timestamp.to_le_bytes());
+        data[PREV_TIMESTAMP_OFFSET..PREV_TIMESTAMP_OFFSET + 8].copy_from_slice(&prev_timestamp.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_parse_price_account() {
+        let data = fake_price_account(15_000_000_000, 5_000_000, -8, 1_700_000_000, PYTH_STATUS_TRADING);
+        let price = parse_price_account(&data).unwrap();
+        assert_eq!(
+            price,
+            OraclePrice {
+                price: 15_000_000_000,
+                conf: 5_000_000,
+                expo: -8,
+                timestamp: 1_700_000_000,
+                prev_timestamp: 1_699_999_999,
+            }
+        );
+
+        // Halted feeds, wrong magic and truncated accounts are rejected
+        let halted = fake_price_account(15_000_000_000, 5_000_000, -8, 1_700_000_000, 0);
+        assert!(parse_price_account(&halted).is_err());
+        let mut bad_magic = data.clone();
+        bad_magic[0] = 0;
+        assert!(parse_price_account(&bad_magic).is_err());
+        assert!(parse_price_account(&data[..200]).is_err());
+    }
+
+    #[test]
+    fn test_check_price() {
+        let price = OraclePrice { price: 10_000, conf: 100, expo: -2, timestamp: 1_000, prev_timestamp: 900 };
+        assert!(check_price(&price, 1_000, 1_030).is_ok());
+
+        // Published before the resolution time, or too old
+        assert!(check_price(&price, 1_001, 1_030).is_err());
+        assert!(check_price(&price, 900, 1_000 + MAX_PRICE_AGE_SECONDS + 1).is_err());
+
+        // Fresh, but published too long after the resolution time
+        assert!(check_price(&price, 1_000 - MAX_PUBLISH_DELAY_SECONDS, 1_030).is_ok());
+        assert_eq!(
+            check_price(&price, 1_000 - MAX_PUBLISH_DELAY_SECONDS - 1, 1_030).unwrap_err(),
+            DegenBetsError::OraclePriceTooLate.into()
+        );
+        let late = OraclePrice { timestamp: 1_000_000, ..price };
+        assert!(check_price(&late, 1_000, 1_000_010).is_err());
+
+        // A later update inside the window is not the first after the
+        // resolution time, so it can't be cherry-picked
+        let first = OraclePrice { timestamp: 1_005, prev_timestamp: 998, ..price };
+        assert!(check_price(&first, 1_000, 1_030).is_ok());
+        let later = OraclePrice { timestamp: 1_020, prev_timestamp: 1_005, ..price };
+        assert_eq!(
+            check_price(&later, 1_000, 1_030).unwrap_err(),
+            DegenBetsError::OraclePriceNotFirst.into()
+        );
+
+        // Confidence wider than MAX_CONFIDENCE_BPS of the price
+        let wide = OraclePrice { conf: 201, ..price };
+        assert!(check_price(&wide, 1_000, 1_030).is_err());
+    }
+}
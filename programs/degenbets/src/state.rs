@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::DegenBetsError;
+use crate::math;
 
 #[account]
 pub struct Config {
@@ -8,16 +10,215 @@ pub struct Config {
     pub treasury_rake_bps: u16,
     pub creator_rake_bps: u16,
     pub market_count: u64,
-    pub paused: bool,
+    /// Bitmask of `PAUSE_*` flags
+    pub pause_flags: u8,
     pub min_trade_lamports: u64,
     pub betting_cutoff_seconds: i64,
     pub challenge_period_seconds: i64,
     pub swap_fee_bps: u16,
     pub bump: u8,
+    pub collateral_mints: Vec<CollateralMint>,
+    pub dispute_bond_lamports: u64,
+    /// Program that must own oracle price accounts, `Pubkey::default()` disables
+    /// oracle resolution
+    pub oracle_program: Pubkey,
+    /// Proposed new authority, set until accepted or cancelled
+    pub pending_authority: Option<Pubkey>,
+    /// Role holders, `Pubkey::default()` when unassigned. The authority
+    /// holds every role implicitly.
+    pub pauser: Pubkey,
+    pub resolver: Pubkey,
+    pub config_admin: Pubkey,
+    pub treasury_claimer: Pubkey,
+    /// Delay between queueing and executing a config change. While zero,
+    /// `update_config` applies changes immediately.
+    pub config_timelock_seconds: i64,
+    /// SOL escrowed by the creator per market, slashed on a creator-fault void
+    pub creator_bond_lamports: u64,
+    /// Swap fee discounts by trader volume, ascending `min_volume`
+    pub fee_tiers: Vec<FeeTier>,
 }
 
 impl Config {
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 2 + 2 + 8 + 1 + 8 + 8 + 8 + 2 + 1;
+    pub const MAX_COLLATERAL_MINTS: usize = 8;
+    pub const DEFAULT_DISPUTE_BOND_LAMPORTS: u64 = 100_000_000; // 0.1 SOL
+    pub const DEFAULT_CONFIG_TIMELOCK_SECONDS: i64 = 86_400; // 24 hours
+    pub const DEFAULT_CREATOR_BOND_LAMPORTS: u64 = 50_000_000; // 0.05 SOL
+    pub const MAX_CONFIG_TIMELOCK_SECONDS: i64 = 30 * 86_400;
+    pub const MAX_FEE_TIERS: usize = 4;
+
+    /// Buys, sells, split/merge and liquidity changes
+    pub const PAUSE_TRADING: u8 = 1 << 0;
+    /// Market creation
+    pub const PAUSE_CREATION: u8 = 1 << 1;
+    /// Winnings, refund, liquidity and creator fee claims
+    pub const PAUSE_CLAIMS: u8 = 1 << 2;
+    pub const PAUSE_ALL: u8 = Self::PAUSE_TRADING | Self::PAUSE_CREATION | Self::PAUSE_CLAIMS;
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 2 + 2 + 8 + 1 + 8 + 8 + 8 + 2 + 1
+        + 4 + Self::MAX_COLLATERAL_MINTS * CollateralMint::SIZE
+        + 8
+        + 32
+        + 1 + 32
+        + 4 * 32
+        + 8
+        + 8
+        + 4 + Self::MAX_FEE_TIERS * FeeTier::SIZE;
+    /// Size before collateral mints, roles and fee tiers, see `migrate_config`
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 2 + 2 + 8 + 1 + 8 + 8 + 8 + 2 + 1;
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+
+    /// Whether `key` may act as `role`: the role's holder or the authority.
+    pub fn has_role(&self, role: AdminRole, key: &Pubkey) -> bool {
+        *key == self.authority || *self.role_holder(role) == *key
+    }
+
+    pub fn role_holder(&self, role: AdminRole) -> &Pubkey {
+        match role {
+            AdminRole::Pauser => &self.pauser,
+            AdminRole::Resolver => &self.resolver,
+            AdminRole::ConfigAdmin => &self.config_admin,
+            AdminRole::TreasuryClaimer => &self.treasury_claimer,
+        }
+    }
+
+    /// Minimum pool liquidity for markets collateralized in `mint`
+    /// (`Pubkey::default()` = native SOL).
+    pub fn min_liquidity_for(&self, mint: &Pubkey) -> Result<u64> {
+        if *mint == Pubkey::default() {
+            return Ok(self.min_liquidity_lamports);
+        }
+        self.collateral_mint(mint).map(|c| c.min_liquidity)
+    }
+
+    /// Minimum trade size for markets collateralized in `mint`.
+    pub fn min_trade_for(&self, mint: &Pubkey) -> Result<u64> {
+        if *mint == Pubkey::default() {
+            return Ok(self.min_trade_lamports);
+        }
+        self.collateral_mint(mint).map(|c| c.min_trade)
+    }
+
+    /// Swap fee charged to `trader` on `market`: the market's fee less the
+    /// discount of the highest tier the trader's volume reaches. Traders
+    /// without a profile pay the full fee.
+    pub fn trade_fee_bps(&self, market: &Market, trader: Option<&TraderProfile>) -> u16 {
+        let volume = trader.map_or(0, |t| t.total_volume);
+        let discount_bps = FeeTier::discount_for(&self.fee_tiers, volume);
+        ((market.swap_fee_bps as u32) * (10000 - discount_bps as u32) / 10000) as u16
+    }
+
+    fn collateral_mint(&self, mint: &Pubkey) -> Result<&CollateralMint> {
+        self.collateral_mints
+            .iter()
+            .find(|c| c.mint == *mint)
+            .ok_or_else(|| error!(DegenBetsError::CollateralNotAllowed))
+    }
+}
+
+/// An SPL mint allowed as market collateral, with its own minimums
+/// (in the mint's base units).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CollateralMint {
+    pub mint: Pubkey,
+    pub min_liquidity: u64,
+    pub min_trade: u64,
+}
+
+impl CollateralMint {
+    pub const SIZE: usize = 32 + 8 + 8;
+}
+
+/// Traders with at least `min_volume` lifetime volume pay `discount_bps`
+/// less of each market's swap fee (10000 = no fee).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct FeeTier {
+    pub min_volume: u64,
+    pub discount_bps: u16,
+}
+
+impl FeeTier {
+    pub const SIZE: usize = 8 + 2;
+
+    /// Discount of the highest tier `volume` reaches
+    pub fn discount_for(tiers: &[FeeTier], volume: u64) -> u16 {
+        tiers
+            .iter()
+            .rev()
+            .find(|tier| volume >= tier.min_volume)
+            .map_or(0, |tier| tier.discount_bps)
+    }
+}
+
+/// Set of config fields to change; `None` leaves a field as is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub struct ConfigParams {
+    pub treasury: Option<Pubkey>,
+    pub min_liquidity_lamports: Option<u64>,
+    pub treasury_rake_bps: Option<u16>,
+    pub creator_rake_bps: Option<u16>,
+    pub min_trade_lamports: Option<u64>,
+    pub betting_cutoff_seconds: Option<i64>,
+    pub challenge_period_seconds: Option<i64>,
+    pub swap_fee_bps: Option<u16>,
+    pub dispute_bond_lamports: Option<u64>,
+    pub oracle_program: Option<Pubkey>,
+    pub config_timelock_seconds: Option<i64>,
+    pub creator_bond_lamports: Option<u64>,
+    /// Replaces the whole tier list
+    pub fee_tiers: Option<Vec<FeeTier>>,
+}
+
+impl ConfigParams {
+    pub const SIZE: usize = 33 + 9 + 3 + 3 + 9 + 9 + 9 + 3 + 9 + 33 + 9 + 9
+        + 1 + 4 + Config::MAX_FEE_TIERS * FeeTier::SIZE;
+
+    /// `config`'s current values for the fields this change sets
+    pub fn current_values(&self, config: &Config) -> ConfigParams {
+        ConfigParams {
+            treasury: self.treasury.map(|_| config.treasury),
+            min_liquidity_lamports: self.min_liquidity_lamports.map(|_| config.min_liquidity_lamports),
+            treasury_rake_bps: self.treasury_rake_bps.map(|_| config.treasury_rake_bps),
+            creator_rake_bps: self.creator_rake_bps.map(|_| config.creator_rake_bps),
+            min_trade_lamports: self.min_trade_lamports.map(|_| config.min_trade_lamports),
+            betting_cutoff_seconds: self.betting_cutoff_seconds.map(|_| config.betting_cutoff_seconds),
+            challenge_period_seconds: self.challenge_period_seconds.map(|_| config.challenge_period_seconds),
+            swap_fee_bps: self.swap_fee_bps.map(|_| config.swap_fee_bps),
+            dispute_bond_lamports: self.dispute_bond_lamports.map(|_| config.dispute_bond_lamports),
+            oracle_program: self.oracle_program.map(|_| config.oracle_program),
+            config_timelock_seconds: self.config_timelock_seconds.map(|_| config.config_timelock_seconds),
+            creator_bond_lamports: self.creator_bond_lamports.map(|_| config.creator_bond_lamports),
+            fee_tiers: self.fee_tiers.as_ref().map(|_| config.fee_tiers.clone()),
+        }
+    }
+}
+
+/// Config change waiting out the timelock. One can be queued at a time.
+#[account]
+pub struct PendingConfigChange {
+    pub proposer: Pubkey,
+    pub params: ConfigParams,
+    pub queued_at: i64,
+    pub eta: i64,
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub const SIZE: usize = 8 + 32 + ConfigParams::SIZE + 8 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum AdminRole {
+    /// `toggle_pause`
+    Pauser,
+    /// `resolve_market`, `void_market`, `settle_dispute`, `set_resolver_committee`
+    Resolver,
+    /// Config, fee and collateral changes
+    ConfigAdmin,
+    /// `claim_treasury_fee`
+    TreasuryClaimer,
 }
 
 #[account]
@@ -39,17 +240,58 @@ pub struct Market {
     pub creator_fee_claimed: bool,
     pub treasury_fee_claimed: bool,
     pub market_id: u64,
+    /// When the market was resolved, or voided
     pub resolved_at: i64,
     pub bump: u8,
     pub treasury_fee: u64,
     pub creator_fee: u64,
     pub treasury_rake_bps: u16,
     pub creator_rake_bps: u16,
+    pub total_lp_shares: u64,
+    /// SPL mint backing the market, `Pubkey::default()` for native SOL
+    pub collateral_mint: Pubkey,
+    pub vault_bump: u8,
+    pub market_type: MarketType,
+    /// Categorical only: one label and one pool reserve per outcome
+    pub outcome_labels: Vec<String>,
+    pub outcome_reserves: Vec<u64>,
+    /// Winning outcome index (binary: 1 = YES, 0 = NO)
+    pub winning_outcome: Option<u8>,
+    /// Scalar only: LONG (YES) pays out linearly from `lower_bound` to `upper_bound`
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+    pub resolved_value: Option<i64>,
+    /// Price condition that lets anyone resolve via `resolve_with_oracle`
+    pub oracle: Option<OracleCondition>,
+    /// Designated resolver allowed to call `resolve_market` besides the authority
+    pub resolver: Option<Pubkey>,
+    /// Signer of `resolve_market`, `Pubkey::default()` for oracle and
+    /// committee resolutions
+    pub resolved_by: Pubkey,
+    /// Frozen by the pauser: no trading or claims on this market
+    pub paused: bool,
+    /// SOL bond escrowed in the market's `CreatorBond` account until
+    /// returned to the creator or slashed
+    pub creator_bond: u64,
+    pub void_reason: Option<VoidReason>,
+    /// Outcome prices snapshotted on void, in `math::VOID_PRICE_PRECISION`
+    /// units (binary: 0 = NO, 1 = YES)
+    pub void_prices: Vec<u64>,
+    /// Collateral paid out of the vault by void refunds so far
+    pub void_refunded: u64,
+    /// Shares refunded so far, summed over every outcome
+    pub void_shares_refunded: u64,
+    /// Resolution was overturned by `settle_dispute`; the creator earns no
+    /// clean-resolution reputation for it
+    pub resolution_overturned: bool,
 }
 
 impl Market {
     pub const MAX_QUESTION_LEN: usize = 256;
     pub const MAX_SOURCE_LEN: usize = 512;
+    pub const MIN_OUTCOMES: usize = 3;
+    pub const MAX_OUTCOMES: usize = 16;
+    pub const MAX_OUTCOME_LABEL_LEN: usize = 32;
     pub const SIZE: usize = 8    // discriminator
         + 32                      // creator
         + 4 + Self::MAX_QUESTION_LEN  // question (string prefix + data)
@@ -70,7 +312,213 @@ impl Market {
         + 8                       // treasury_fee
         + 8                       // creator_fee
         + 2                       // treasury_rake_bps
-        + 2;                      // creator_rake_bps
+        + 2                       // creator_rake_bps
+        + 8                       // total_lp_shares
+        + 32                      // collateral_mint
+        + 1                       // vault_bump
+        + 1                       // market_type
+        + 4                       // outcome_labels (empty for binary)
+        + 4                       // outcome_reserves (empty for binary)
+        + 1 + 1                   // winning_outcome (Option<u8>)
+        + 8                       // lower_bound
+        + 8                       // upper_bound
+        + 1 + 8                   // resolved_value (Option<i64>)
+        + 1 + OracleCondition::SIZE   // oracle (Option<OracleCondition>)
+        + 1 + 32                  // resolver (Option<Pubkey>)
+        + 32                      // resolved_by
+        + 1                       // paused
+        + 8                       // creator_bond
+        + 1 + 1                   // void_reason (Option<VoidReason>)
+        + 4 + 2 * 8               // void_prices (binary)
+        + 8                       // void_refunded
+        + 8                       // void_shares_refunded
+        + 1;                      // resolution_overturned
+    /// Size up to `creator_rake_bps`, before LP shares and vaults existed,
+    /// see `migrate_market`
+    pub const LEGACY_SIZE: usize = 8 + 32 + 4 + Self::MAX_QUESTION_LEN + 4 + Self::MAX_SOURCE_LEN
+        + 8 + 8 + 8 + 8 + 2 + 8 + 1 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 8 + 8 + 2 + 2;
+
+    /// Number of outcomes `resolve_market` can pick from
+    pub fn outcome_count(&self) -> usize {
+        match self.market_type {
+            MarketType::Categorical => self.outcome_reserves.len(),
+            _ => 2,
+        }
+    }
+
+    /// Validate a resolution without applying it.
+    pub fn check_resolution(&self, outcome: u8, scalar_value: Option<i64>) -> Result<()> {
+        match self.market_type {
+            MarketType::Scalar => {
+                require!(scalar_value.is_some(), DegenBetsError::MissingScalarValue);
+            }
+            _ => {
+                require!(scalar_value.is_none(), DegenBetsError::InvalidMarketType);
+                require!(
+                    (outcome as usize) < self.outcome_count(),
+                    DegenBetsError::InvalidOutcomeIndex
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a resolution: a winning outcome index for binary/categorical
+    /// markets, or the observed value for scalar markets.
+    pub fn set_resolution(&mut self, outcome: u8, scalar_value: Option<i64>) -> Result<()> {
+        self.check_resolution(outcome, scalar_value)?;
+        match self.market_type {
+            MarketType::Scalar => {
+                self.resolved_value = scalar_value;
+                self.outcome = None;
+                self.winning_outcome = None;
+            }
+            _ => {
+                self.winning_outcome = Some(outcome);
+                self.outcome = match self.market_type {
+                    MarketType::Categorical => None,
+                    _ => Some(outcome == 1),
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// Set-equivalent winning shares of a YES/NO holding after resolution.
+    /// Scalar markets split each set between LONG (YES) and SHORT (NO).
+    pub fn resolved_winning_shares(&self, yes_shares: u64, no_shares: u64) -> Result<u64> {
+        if self.market_type == MarketType::Scalar {
+            let value = self.resolved_value.ok_or(DegenBetsError::MarketNotResolved)?;
+            let long_fraction = math::scalar_long_fraction(value, self.lower_bound, self.upper_bound)?;
+            return math::calc_scalar_settlement(yes_shares, no_shares, long_fraction);
+        }
+
+        match self.outcome {
+            Some(true) => Ok(yes_shares),
+            Some(false) => Ok(no_shares),
+            None => err!(DegenBetsError::MarketNotResolved),
+        }
+    }
+
+    /// Freeze the pool's outcome prices when the market is voided. Refunds
+    /// value every share at these prices, so later LP withdrawals that move
+    /// the reserves don't change what a position is worth.
+    pub fn snapshot_void_prices(&mut self) {
+        self.void_prices = match self.market_type {
+            MarketType::Categorical => math::void_prices(&self.outcome_reserves),
+            _ => math::void_prices(&[self.no_reserve, self.yes_reserve]),
+        };
+    }
+
+    /// Void refund for a YES/NO (binary or scalar) holding.
+    pub fn binary_void_refund(&self, yes_shares: u64, no_shares: u64) -> Result<u64> {
+        math::calc_void_refund(&[no_shares, yes_shares], &self.void_prices)
+    }
+
+    /// Account for a void refund of `shares` (per outcome) paid out of the
+    /// vault. Refunds can never add up to more than the collateral backing
+    /// the market.
+    pub fn record_void_refund(&mut self, amount: u64, shares: &[u64]) -> Result<()> {
+        self.void_refunded = self.void_refunded
+            .checked_add(amount)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        require!(
+            self.void_refunded <= self.total_minted,
+            DegenBetsError::VaultUndercollateralized
+        );
+        for &s in shares {
+            self.void_shares_refunded = self.void_shares_refunded
+                .checked_add(s)
+                .ok_or(DegenBetsError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Whether every share of a voided market has been refunded. Each outcome
+    /// has exactly `total_minted` shares across holders and the pool, so this
+    /// is exact where the refunded collateral falls short by rounding.
+    pub fn void_settled(&self) -> bool {
+        self.void_shares_refunded as u128
+            == self.total_minted as u128 * self.outcome_count() as u128
+    }
+
+    /// True until anyone other than the creator has traded or added
+    /// liquidity: the pool still holds exactly the creator's opening sets.
+    pub fn is_untouched(&self) -> bool {
+        let liquidity = self.initial_liquidity;
+        let reserves_untouched = match self.market_type {
+            MarketType::Categorical => self.outcome_reserves.iter().all(|&r| r == liquidity),
+            _ => self.yes_reserve == liquidity && self.no_reserve == liquidity,
+        };
+        self.total_minted == liquidity && self.total_lp_shares == liquidity && reserves_untouched
+    }
+
+    /// Account size for a categorical market with `outcome_count` outcomes
+    pub fn space_for(outcome_count: usize) -> usize {
+        Self::SIZE
+            + outcome_count * (4 + Self::MAX_OUTCOME_LABEL_LEN)
+            + outcome_count * 8
+            + outcome_count * 8
+    }
+}
+
+/// Binary markets resolve YES when `price <comparator> threshold`; scalar
+/// markets resolve to the price itself. Prices are in the feed's fixed-point
+/// units, so `expo` must match the feed's exponent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct OracleCondition {
+    pub feed: Pubkey,
+    pub comparator: OracleComparator,
+    pub threshold: i64,
+    pub expo: i32,
+}
+
+impl OracleCondition {
+    pub const SIZE: usize = 32 + 1 + 8 + 4;
+
+    /// Winning outcome index for a binary market (1 = YES, 0 = NO)
+    pub fn outcome_for(&self, price: i64) -> u8 {
+        let yes = match self.comparator {
+            OracleComparator::GreaterThan => price > self.threshold,
+            OracleComparator::GreaterOrEqual => price >= self.threshold,
+            OracleComparator::LessThan => price < self.threshold,
+            OracleComparator::LessOrEqual => price <= self.threshold,
+        };
+        yes as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum OracleComparator {
+    GreaterThan,
+    GreaterOrEqual,
+    LessThan,
+    LessOrEqual,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MarketType {
+    Binary,
+    Categorical,
+    /// Numeric range; YES/NO shares act as LONG/SHORT
+    Scalar,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VoidReason {
+    /// Ambiguous question, bad source or similar; slashes the creator bond
+    CreatorFault,
+    SourceUnavailable,
+    EventCancelled,
+    /// Unresolved long past the resolution time (`reclaim_stale_market`)
+    Stale,
+    Other,
+}
+
+impl VoidReason {
+    pub fn is_creator_fault(&self) -> bool {
+        *self == VoidReason::CreatorFault
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -78,6 +526,8 @@ pub enum MarketStatus {
     Open,
     Resolved,
     Voided,
+    /// Resolution challenged during the challenge period; claims are frozen
+    Disputed,
 }
 
 #[account]
@@ -88,10 +538,251 @@ pub struct Position {
     pub no_shares: u64,
     pub claimed: bool,
     pub bump: u8,
+    /// Collateral paid into the position (buys, splits)
+    pub total_cost_lamports: u64,
+    /// Collateral paid out of the position (sells, merges, claims)
+    pub total_proceeds_lamports: u64,
+    /// Proceeds minus the cost of the shares they disposed of
+    pub realized_pnl: i64,
+    /// Cost of the YES shares still held
+    pub yes_cost_basis: u64,
+    /// Cost of the NO shares still held
+    pub no_cost_basis: u64,
 }
 
 impl Position {
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 8 + 8 + 8 + 8 + 8;
+    /// Size before cost basis tracking, see `migrate_position`
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
+
+    /// Shares held on `side` (true = YES)
+    pub fn shares(&self, side: bool) -> u64 {
+        if side { self.yes_shares } else { self.no_shares }
+    }
+
+    pub fn shares_mut(&mut self, side: bool) -> &mut u64 {
+        if side { &mut self.yes_shares } else { &mut self.no_shares }
+    }
+
+    /// Cost basis of the shares still held, both sides
+    pub fn open_cost_basis(&self) -> Result<u64> {
+        Ok(self.yes_cost_basis
+            .checked_add(self.no_cost_basis)
+            .ok_or(DegenBetsError::MathOverflow)?)
+    }
+
+    /// Record collateral paid into the position, split by the side it bought.
+    pub fn record_cost(&mut self, yes_lamports: u64, no_lamports: u64) -> Result<()> {
+        self.yes_cost_basis = self.yes_cost_basis
+            .checked_add(yes_lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        self.no_cost_basis = self.no_cost_basis
+            .checked_add(no_lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        self.total_cost_lamports = self.total_cost_lamports
+            .checked_add(yes_lamports)
+            .ok_or(DegenBetsError::MathOverflow)?
+            .checked_add(no_lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Record collateral received for `yes_disposed` YES and `no_disposed` NO
+    /// shares, realizing PnL against each side's average cost. Call before
+    /// the shares are deducted.
+    pub fn record_proceeds(&mut self, lamports: u64, yes_disposed: u64, no_disposed: u64) -> Result<()> {
+        let yes_released = math::calc_released_cost(self.yes_cost_basis, yes_disposed, self.yes_shares)?;
+        let no_released = math::calc_released_cost(self.no_cost_basis, no_disposed, self.no_shares)?;
+        self.yes_cost_basis -= yes_released;
+        self.no_cost_basis -= no_released;
+        self.total_proceeds_lamports = self.total_proceeds_lamports
+            .checked_add(lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        self.realized_pnl = realize(self.realized_pnl, lamports, yes_released + no_released)?;
+        Ok(())
+    }
+
+    /// Record a claim, which disposes of everything held.
+    pub fn record_claim(&mut self, lamports: u64) -> Result<()> {
+        self.record_proceeds(lamports, self.yes_shares, self.no_shares)
+    }
+}
+
+/// Per-outcome share balances in a categorical market. Lives at the same
+/// `[b"position", market, user]` address a `Position` would for binary markets.
+#[account]
+pub struct OutcomePosition {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub shares: Vec<u64>,
+    pub claimed: bool,
+    pub bump: u8,
+    pub total_cost_lamports: u64,
+    pub total_proceeds_lamports: u64,
+    pub realized_pnl: i64,
+    /// Cost of the shares still held, per outcome
+    pub cost_basis: Vec<u64>,
+}
+
+impl OutcomePosition {
+    pub fn space_for(outcome_count: usize) -> usize {
+        8 + 32 + 32 + 4 + 8 * outcome_count + 1 + 1 + 8 + 8 + 8 + 4 + 8 * outcome_count
+    }
+
+    /// Cost basis of the shares still held, every outcome
+    pub fn open_cost_basis(&self) -> Result<u64> {
+        Ok(self.cost_basis
+            .iter()
+            .try_fold(0u64, |acc, &c| acc.checked_add(c))
+            .ok_or(DegenBetsError::MathOverflow)?)
+    }
+
+    /// Record collateral paid for shares of `outcome`.
+    pub fn record_cost(&mut self, outcome: usize, lamports: u64) -> Result<()> {
+        self.cost_basis[outcome] = self.cost_basis[outcome]
+            .checked_add(lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        self.total_cost_lamports = self.total_cost_lamports
+            .checked_add(lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Same as `Position::record_proceeds`, for `shares` of one outcome.
+    pub fn record_sale(&mut self, outcome: usize, lamports: u64, shares: u64) -> Result<()> {
+        let released = math::calc_released_cost(self.cost_basis[outcome], shares, self.shares[outcome])?;
+        self.cost_basis[outcome] -= released;
+        self.record_released(lamports, released)
+    }
+
+    /// Record a claim, which disposes of every outcome's shares.
+    pub fn record_claim(&mut self, lamports: u64) -> Result<()> {
+        let released = self.open_cost_basis()?;
+        self.cost_basis.iter_mut().for_each(|c| *c = 0);
+        self.record_released(lamports, released)
+    }
+
+    fn record_released(&mut self, lamports: u64, released: u64) -> Result<()> {
+        self.total_proceeds_lamports = self.total_proceeds_lamports
+            .checked_add(lamports)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        self.realized_pnl = realize(self.realized_pnl, lamports, released)?;
+        Ok(())
+    }
+}
+
+fn realize(realized_pnl: i64, proceeds: u64, released_cost: u64) -> Result<i64> {
+    let pnl = realized_pnl as i128 + proceeds as i128 - released_cost as i128;
+    i64::try_from(pnl).map_err(|_| DegenBetsError::MathOverflow.into())
+}
+
+#[account]
+pub struct LiquidityPosition {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub lp_shares: u64,
+    pub bump: u8,
+}
+
+impl LiquidityPosition {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+}
+
+/// Open challenge against a market's resolution, holding the disputer's SOL bond
+#[account]
+pub struct Dispute {
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+    pub disputed_at: i64,
+    pub bump: u8,
+}
+
+impl Dispute {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+}
+
+/// Committee that can resolve or void markets by M-of-N vote, alongside
+/// the global authority
+#[account]
+pub struct ResolverCommittee {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub bump: u8,
+}
+
+impl ResolverCommittee {
+    pub const MAX_MEMBERS: usize = 10;
+    pub const SIZE: usize = 8 + 4 + Self::MAX_MEMBERS * 32 + 1 + 1;
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CommitteeVote {
+    /// Same arguments as `resolve_market`
+    Resolve { outcome: u8, scalar_value: Option<i64> },
+    Void { reason_code: VoidReason },
+}
+
+impl CommitteeVote {
+    pub const SIZE: usize = 1 + 1 + 1 + 8;
+
+    /// Canonical form of the vote on a `market_type` market. Scalar markets
+    /// resolve by value alone, so the ignored `outcome` is zeroed and votes
+    /// for the same value tally together.
+    pub fn normalized(self, market_type: &MarketType) -> Self {
+        match self {
+            CommitteeVote::Resolve { scalar_value, .. } if *market_type == MarketType::Scalar => {
+                CommitteeVote::Resolve { outcome: 0, scalar_value }
+            }
+            vote => vote,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ResolutionVote {
+    pub voter: Pubkey,
+    pub vote: CommitteeVote,
+}
+
+impl ResolutionVote {
+    pub const SIZE: usize = 32 + CommitteeVote::SIZE;
+}
+
+/// Committee votes cast on one market; one entry per member, latest vote wins
+#[account]
+pub struct ResolutionVotes {
+    pub market: Pubkey,
+    pub votes: Vec<ResolutionVote>,
+    pub bump: u8,
+    /// Member who paid the rent, refunded by `close_resolution_votes`
+    pub payer: Pubkey,
+}
+
+impl ResolutionVotes {
+    pub const SIZE: usize = 8 + 32 + 4 + ResolverCommittee::MAX_MEMBERS * ResolutionVote::SIZE + 1 + 32;
+
+    /// Record `voter`'s vote, replacing any earlier one. Votes from members
+    /// since removed from the committee are dropped.
+    pub fn cast(&mut self, committee: &ResolverCommittee, voter: Pubkey, vote: CommitteeVote) {
+        self.votes.retain(|v| committee.is_member(&v.voter));
+        match self.votes.iter_mut().find(|v| v.voter == voter) {
+            Some(existing) => existing.vote = vote,
+            None => self.votes.push(ResolutionVote { voter, vote }),
+        }
+    }
+
+    /// Votes for `vote` from current committee members
+    pub fn tally(&self, committee: &ResolverCommittee, vote: &CommitteeVote) -> u8 {
+        self.votes
+            .iter()
+            .filter(|v| committee.is_member(&v.voter) && v.vote == *vote)
+            .count() as u8
+    }
 }
 
 #[account]
@@ -104,8 +795,449 @@ pub struct CreatorProfile {
     pub total_fees_earned: u64,
     pub reputation_score: u32,
     pub bump: u8,
+    /// Volume bonus points already credited to `reputation_score`
+    pub volume_bonus_awarded: u32,
 }
 
+// Reputation model: every creator starts at INITIAL_REPUTATION. Each clean
+// resolution (claimed after the challenge period) earns CLEAN_RESOLUTION_BONUS
+// plus any volume points newly earned by `total_volume_generated`, up to
+// MAX_VOLUME_BONUS in total. Creator-fault voids and stale reclaims cost points. The score
+// is capped at MAX_REPUTATION and unlocks the perks in REPUTATION_TIERS.
 impl CreatorProfile {
-    pub const SIZE: usize = 8 + 32 + 4 + 4 + 4 + 8 + 8 + 4 + 1;
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 4 + 8 + 8 + 4 + 1 + 4;
+    /// Size before `volume_bonus_awarded`, see `migrate_creator_profile`
+    pub const LEGACY_SIZE: usize = Self::SIZE - 4;
+
+    pub const INITIAL_REPUTATION: u32 = 100;
+    pub const MAX_REPUTATION: u32 = 1000;
+    pub const CLEAN_RESOLUTION_BONUS: u32 = 5;
+    /// One bonus point per 10 SOL of volume
+    pub const VOLUME_PER_BONUS_POINT: u64 = 10_000_000_000;
+    pub const MAX_VOLUME_BONUS: u32 = 200;
+    pub const VOID_PENALTY: u32 = 10;
+    pub const STALE_RECLAIM_PENALTY: u32 = 25;
+
+    /// (min score, min liquidity discount bps, creator rake bonus bps),
+    /// highest tier first
+    pub const REPUTATION_TIERS: [(u32, u16, u16); 2] = [(500, 5000, 50), (250, 2500, 25)];
+
+    pub fn record_clean_resolution(&mut self) {
+        let earned = (self.total_volume_generated / Self::VOLUME_PER_BONUS_POINT)
+            .min(Self::MAX_VOLUME_BONUS as u64) as u32;
+        let volume_bonus = earned.saturating_sub(self.volume_bonus_awarded);
+        self.volume_bonus_awarded = self.volume_bonus_awarded.max(earned);
+
+        self.reputation_score = self.reputation_score
+            .saturating_add(Self::CLEAN_RESOLUTION_BONUS + volume_bonus)
+            .min(Self::MAX_REPUTATION);
+    }
+
+    pub fn record_void(&mut self, creator_fault: bool) {
+        self.markets_voided += 1;
+        if creator_fault {
+            self.reputation_score = self.reputation_score.saturating_sub(Self::VOID_PENALTY);
+        }
+    }
+
+    pub fn record_stale_reclaim(&mut self) {
+        self.markets_voided += 1;
+        self.reputation_score = self.reputation_score.saturating_sub(Self::STALE_RECLAIM_PENALTY);
+    }
+
+    fn tier(&self) -> Option<(u32, u16, u16)> {
+        Self::REPUTATION_TIERS
+            .into_iter()
+            .find(|&(min_score, _, _)| self.reputation_score >= min_score)
+    }
+
+    /// Minimum pool liquidity for this creator, discounted from `base` by tier
+    pub fn min_liquidity(&self, base: u64) -> u64 {
+        let discount_bps = self.tier().map_or(0, |(_, discount, _)| discount);
+        ((base as u128) * (10000 - discount_bps as u128) / 10000) as u64
+    }
+
+    /// Creator rake for this creator's new markets, raised by tier but never
+    /// past what the treasury rake leaves
+    pub fn creator_rake_bps(&self, config: &Config) -> u16 {
+        let bonus_bps = self.tier().map_or(0, |(_, _, bonus)| bonus);
+        config.creator_rake_bps
+            .saturating_add(bonus_bps)
+            .min(10000u16.saturating_sub(config.treasury_rake_bps))
+    }
+}
+
+/// Escrow for a market's creator bond, `[b"bond", market]`. Holds its own
+/// rent plus `Market::creator_bond` until the bond is returned or slashed.
+#[account]
+pub struct CreatorBond {
+    pub market: Pubkey,
+    pub bump: u8,
+}
+
+impl CreatorBond {
+    pub const SIZE: usize = 8 + 32 + 1;
+}
+
+/// Track record of a wallet resolving markets via `resolve_market`
+#[account]
+pub struct ResolverProfile {
+    pub wallet: Pubkey,
+    pub markets_resolved: u32,
+    /// Resolutions later voided or overturned on dispute
+    pub markets_overturned: u32,
+    pub total_volume_resolved: u64,
+    pub reputation_score: u32,
+    pub bump: u8,
+}
+
+impl ResolverProfile {
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 8 + 4 + 1;
+
+    /// Record a resolution by this wallet being voided or overturned.
+    pub fn record_overturn(&mut self) {
+        self.markets_overturned += 1;
+        // Lose 10 reputation points per overturn, minimum 0
+        self.reputation_score = self.reputation_score.saturating_sub(10);
+    }
+}
+
+/// Lifetime trading record of a wallet across every market type, updated by
+/// each buy, sell, winnings claim and refund claim it is passed to
+#[account]
+pub struct TraderProfile {
+    pub wallet: Pubkey,
+    /// Collateral traded through the pool, buys plus sells
+    pub total_volume: u64,
+    pub markets_traded: u32,
+    /// Claimed markets closed with a realized profit / with none
+    pub wins: u32,
+    pub losses: u32,
+    /// Sum of realized PnL across every position
+    pub net_pnl: i64,
+    /// Consecutive wins, reset by a loss
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub bump: u8,
+}
+
+impl TraderProfile {
+    pub const SIZE: usize = 8 + 32 + 8 + 4 + 4 + 4 + 8 + 4 + 4 + 1;
+
+    /// Fill in a profile created by `init_if_needed` in this instruction
+    pub fn init_if_new(&mut self, wallet: Pubkey, bump: u8) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_trade(&mut self, volume: u64, new_market: bool) -> Result<()> {
+        self.total_volume = self.total_volume
+            .checked_add(volume)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        if new_market {
+            self.markets_traded += 1;
+        }
+        Ok(())
+    }
+
+    /// Add PnL realized by a sell or claim
+    pub fn record_pnl(&mut self, pnl: i64) -> Result<()> {
+        self.net_pnl = self.net_pnl
+            .checked_add(pnl)
+            .ok_or(DegenBetsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Count a claimed market as a win or loss from its position's
+    /// lifetime realized PnL.
+    pub fn record_settlement(&mut self, position_pnl: i64) {
+        if position_pnl > 0 {
+            self.wins += 1;
+            self.current_streak += 1;
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            self.losses += 1;
+            self.current_streak = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migration;
+
+    fn profile(reputation_score: u32) -> CreatorProfile {
+        CreatorProfile {
+            wallet: Pubkey::default(),
+            markets_created: 0,
+            markets_resolved: 0,
+            markets_voided: 0,
+            total_volume_generated: 0,
+            total_fees_earned: 0,
+            reputation_score,
+            bump: 0,
+            volume_bonus_awarded: 0,
+        }
+    }
+
+    #[test]
+    fn test_reputation_bonuses_and_penalties() {
+        let mut p = profile(CreatorProfile::INITIAL_REPUTATION);
+
+        // 35 SOL of volume: 3 volume points, credited once
+        p.total_volume_generated = 35_000_000_000;
+        p.record_clean_resolution();
+        assert_eq!(p.reputation_score, 100 + 5 + 3);
+        p.record_clean_resolution();
+        assert_eq!(p.reputation_score, 108 + 5);
+
+        p.record_void(true);
+        p.record_void(false);
+        p.record_stale_reclaim();
+        assert_eq!(p.reputation_score, 113 - 10 - 25);
+        assert_eq!(p.markets_voided, 3);
+
+        // Capped at both ends
+        let mut high = profile(CreatorProfile::MAX_REPUTATION - 1);
+        high.record_clean_resolution();
+        assert_eq!(high.reputation_score, CreatorProfile::MAX_REPUTATION);
+        let mut low = profile(5);
+        low.record_stale_reclaim();
+        assert_eq!(low.reputation_score, 0);
+    }
+
+    #[test]
+    fn test_reputation_perks() {
+        assert_eq!(profile(100).min_liquidity(1_000_000_000), 1_000_000_000);
+        assert_eq!(profile(250).min_liquidity(1_000_000_000), 750_000_000);
+        assert_eq!(profile(900).min_liquidity(1_000_000_000), 500_000_000);
+    }
+
+    #[test]
+    fn test_position_realized_pnl() {
+        let mut p = Position {
+            market: Pubkey::default(),
+            user: Pubkey::default(),
+            yes_shares: 0,
+            no_shares: 0,
+            claimed: false,
+            bump: 0,
+            total_cost_lamports: 0,
+            total_proceeds_lamports: 0,
+            realized_pnl: 0,
+            yes_cost_basis: 0,
+            no_cost_basis: 0,
+        };
+
+        // Buy 200 YES for 100, sell half of them for 80: 30 realized
+        p.record_cost(100, 0).unwrap();
+        p.yes_shares = 200;
+        p.record_proceeds(80, 100, 0).unwrap();
+        p.yes_shares = 100;
+        assert_eq!(p.realized_pnl, 30);
+        assert_eq!(p.open_cost_basis().unwrap(), 50);
+
+        // Losing claim pays nothing and realizes the rest of the cost
+        p.record_claim(0).unwrap();
+        assert_eq!(p.realized_pnl, -20);
+        assert_eq!(p.open_cost_basis().unwrap(), 0);
+        assert_eq!(
+            p.realized_pnl,
+            p.total_proceeds_lamports as i64 - p.total_cost_lamports as i64
+        );
+    }
+
+    #[test]
+    fn test_position_cost_basis_per_side() {
+        let mut p = Position {
+            market: Pubkey::default(),
+            user: Pubkey::default(),
+            yes_shares: 0,
+            no_shares: 0,
+            claimed: false,
+            bump: 0,
+            total_cost_lamports: 0,
+            total_proceeds_lamports: 0,
+            realized_pnl: 0,
+            yes_cost_basis: 0,
+            no_cost_basis: 0,
+        };
+
+        // 100 YES for 80, 100 NO for 20: selling the cheap NO side at 30
+        // only releases what NO cost
+        p.record_cost(80, 0).unwrap();
+        p.yes_shares = 100;
+        p.record_cost(0, 20).unwrap();
+        p.no_shares = 100;
+        p.record_proceeds(30, 0, 100).unwrap();
+        p.no_shares = 0;
+        assert_eq!(p.realized_pnl, 10);
+        assert_eq!((p.yes_cost_basis, p.no_cost_basis), (80, 0));
+
+        let mut o = OutcomePosition {
+            market: Pubkey::default(),
+            user: Pubkey::default(),
+            shares: vec![0; 3],
+            claimed: false,
+            bump: 0,
+            total_cost_lamports: 0,
+            total_proceeds_lamports: 0,
+            realized_pnl: 0,
+            cost_basis: vec![0; 3],
+        };
+        o.record_cost(0, 60).unwrap();
+        o.shares[0] = 100;
+        o.record_cost(2, 10).unwrap();
+        o.shares[2] = 100;
+        o.record_sale(2, 5, 50).unwrap();
+        o.shares[2] = 50;
+        assert_eq!(o.realized_pnl, 0);
+        assert_eq!(o.cost_basis, vec![60, 0, 5]);
+
+        // Winning claim on outcome 0 closes out everything
+        o.record_claim(90).unwrap();
+        assert_eq!(o.realized_pnl, 25);
+        assert_eq!(o.open_cost_basis().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_migrate_legacy_position() {
+        let market = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let mut data = Position::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(market.as_ref());
+        data.extend_from_slice(user.as_ref());
+        data.extend_from_slice(&700u64.to_le_bytes());
+        data.extend_from_slice(&300u64.to_le_bytes());
+        data.extend_from_slice(&[0, 254]);
+        assert_eq!(data.len(), Position::LEGACY_SIZE);
+
+        // Too short to load until resized
+        assert!(Position::try_deserialize(&mut &data[..]).is_err());
+        migration::check_legacy(&data, Position::DISCRIMINATOR, Position::LEGACY_SIZE, Position::SIZE)
+            .unwrap();
+
+        data.resize(Position::SIZE, 0);
+        let mut p = Position::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!((p.market, p.user), (market, user));
+        assert_eq!((p.yes_shares, p.no_shares, p.claimed, p.bump), (700, 300, false, 254));
+        assert_eq!(p.open_cost_basis().unwrap(), 0);
+        assert_eq!((p.total_cost_lamports, p.total_proceeds_lamports, p.realized_pnl), (0, 0, 0));
+
+        // Tracks cost from here on, and can't be migrated twice
+        p.record_cost(50, 0).unwrap();
+        assert_eq!(p.yes_cost_basis, 50);
+        assert!(migration::check_legacy(&data, Position::DISCRIMINATOR, Position::LEGACY_SIZE, Position::SIZE)
+            .is_err());
+    }
+
+    #[test]
+    fn test_trader_streaks() {
+        let mut t = TraderProfile {
+            wallet: Pubkey::default(),
+            total_volume: 0,
+            markets_traded: 0,
+            wins: 0,
+            losses: 0,
+            net_pnl: 0,
+            current_streak: 0,
+            best_streak: 0,
+            bump: 0,
+        };
+        for pnl in [10, 5, -3, 1] {
+            t.record_pnl(pnl).unwrap();
+            t.record_settlement(pnl);
+        }
+        assert_eq!((t.wins, t.losses), (3, 1));
+        assert_eq!((t.current_streak, t.best_streak), (1, 2));
+        assert_eq!(t.net_pnl, 13);
+    }
+
+    #[test]
+    fn test_losing_claim_records_loss() {
+        let mut p = Position {
+            market: Pubkey::default(),
+            user: Pubkey::default(),
+            yes_shares: 0,
+            no_shares: 0,
+            claimed: false,
+            bump: 0,
+            total_cost_lamports: 0,
+            total_proceeds_lamports: 0,
+            realized_pnl: 0,
+            yes_cost_basis: 0,
+            no_cost_basis: 0,
+        };
+        let mut t = TraderProfile {
+            wallet: Pubkey::default(),
+            total_volume: 0,
+            markets_traded: 0,
+            wins: 0,
+            losses: 0,
+            net_pnl: 0,
+            current_streak: 2,
+            best_streak: 2,
+            bump: 0,
+        };
+
+        // 100 YES for 80 in a market that resolves NO: the claim pays
+        // nothing and the whole cost is lost, as claim_winnings records it
+        p.record_cost(80, 0).unwrap();
+        p.yes_shares = 100;
+        let pnl_before = p.realized_pnl;
+        p.record_claim(0).unwrap();
+        t.record_pnl(p.realized_pnl - pnl_before).unwrap();
+        t.record_settlement(p.realized_pnl);
+
+        assert_eq!(p.realized_pnl, -80);
+        assert_eq!((t.wins, t.losses), (0, 1));
+        assert_eq!((t.current_streak, t.best_streak), (0, 2));
+        assert_eq!(t.net_pnl, -80);
+    }
+
+    #[test]
+    fn test_scalar_votes_tally_by_value() {
+        let members = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let committee = ResolverCommittee {
+            members: members.to_vec(),
+            threshold: 2,
+            bump: 0,
+        };
+        let mut votes = ResolutionVotes {
+            market: Pubkey::default(),
+            votes: Vec::new(),
+            bump: 0,
+            payer: Pubkey::default(),
+        };
+
+        // Same value, different (ignored) outcome bytes
+        let a = CommitteeVote::Resolve { outcome: 0, scalar_value: Some(42) };
+        let b = CommitteeVote::Resolve { outcome: 1, scalar_value: Some(42) };
+        votes.cast(&committee, members[0], a.normalized(&MarketType::Scalar));
+        let b = b.normalized(&MarketType::Scalar);
+        votes.cast(&committee, members[1], b.clone());
+        assert_eq!(votes.tally(&committee, &b), 2);
+
+        // Outcome still decides binary votes
+        let yes = CommitteeVote::Resolve { outcome: 1, scalar_value: None };
+        assert!(yes.clone().normalized(&MarketType::Binary) == yes);
+        let other = CommitteeVote::Resolve { outcome: 0, scalar_value: Some(43) };
+        votes.cast(&committee, members[1], other.normalized(&MarketType::Scalar));
+        assert_eq!(votes.tally(&committee, &b), 1);
+    }
+
+    #[test]
+    fn test_fee_tier_discount() {
+        let tiers = [
+            FeeTier { min_volume: 100, discount_bps: 1000 },
+            FeeTier { min_volume: 1_000, discount_bps: 5000 },
+        ];
+        assert_eq!(FeeTier::discount_for(&tiers, 99), 0);
+        assert_eq!(FeeTier::discount_for(&tiers, 100), 1000);
+        assert_eq!(FeeTier::discount_for(&tiers, 5_000), 5000);
+        assert_eq!(FeeTier::discount_for(&[], 5_000), 0);
+    }
 }